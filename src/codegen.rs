@@ -1,6 +1,15 @@
-//! Submodule containing all the macros that generate `SignCast`, `Widen`, and `Truncate` implementations
+//! Submodule containing all the macros that generate `SignCast`, `Widen`, `Truncate`, and their
+//! checked and saturating companion implementations
 
-use crate::{SignCast, Truncate, TruncateFrom, Widen, WidenFrom};
+use crate::{
+    BitCast, BitField, BitFieldOf, CastChecked, CastCheckedFrom, CastError, CastErrorKind,
+    ExtendFrom, FloatFrom, Int, IntFrom, LaneOf, Narrow, NarrowFrom, NarrowerInt, OnesComplement,
+    OrderedBits, OrderedSignCast, RoundedFloatFrom, Rounding, SaturatingSignCast,
+    SaturatingTruncate, SaturatingTruncateFrom, SignCast, SignMagnitude, Split, SplitLanes,
+    ToFloat, ToFloatRounded, ToInt, Truncate, TruncateFrom, TruncateHigh, TruncateHighFrom,
+    TrySignCast, TryTruncate, TryTruncateFrom, Widen, WidenExtend, WidenExtendFrom, WidenFrom,
+    WidenSigned, WidenSignedFrom, Widening, WiderInt, ZigZag,
+};
 
 /// Implements `WidenFrom` for integer types, note that the argument order to this macro is critical
 macro_rules! widen_from_order {
@@ -20,15 +29,60 @@ macro_rules! widen_from_order {
 widen_from_order!(u128, u64, u32, u16, u8);
 widen_from_order!(i128, i64, i32, i16, i8);
 
-/// Implements `TruncateFrom` for integer types, note that the argument order to this macro is critical
-macro_rules! truncate_from_order {
-    ($t:ty, $($from:ty),+) => {
+/// Implements `TruncateFrom` and its checked companions for `(from, into)` pairs
+macro_rules! truncate_pairs {
+    ($(($from:ty, $t:ty)),+) => {
         $(
         impl TruncateFrom<$from> for $t {
             #[inline]
+            #[allow(clippy::cast_possible_truncation)]
             fn truncate_from(v: $from) -> $t { v as $t }
         }
+
+        impl TryTruncateFrom<$from> for $t {
+            #[inline]
+            fn try_truncate_from(v: $from) -> Result<$t, CastError<$from>> {
+                <$t>::try_from(v).map_err(|_| {
+                    let kind = if v > <$from>::widen_from(<$t>::MAX) {
+                        CastErrorKind::Overflow
+                    } else {
+                        CastErrorKind::Underflow
+                    };
+
+                    CastError::new::<$t>(v, kind)
+                })
+            }
+
+            #[inline]
+            fn overflowing_truncate_from(v: $from) -> ($t, bool) {
+                (<$t>::truncate_from(v), <$t>::try_from(v).is_err())
+            }
+        }
+
+        impl SaturatingTruncateFrom<$from> for $t {
+            #[inline]
+            fn saturating_truncate_from(v: $from) -> $t {
+                let min = <$from>::widen_from(<$t>::MIN);
+                let max = <$from>::widen_from(<$t>::MAX);
+
+                <$t>::truncate_from(v.clamp(min, max))
+            }
+        }
+
+        impl TruncateHighFrom<$from> for $t {
+            #[inline]
+            fn truncate_high_from(v: $from) -> $t {
+                <$t>::truncate_from(v >> (<$from>::BITS - <$t>::BITS))
+            }
+        }
         )*
+    };
+}
+
+/// Implements `TruncateFrom` for integer types, note that the argument order to this macro is critical
+macro_rules! truncate_from_order {
+    ($t:ty, $($from:ty),+) => {
+        truncate_pairs!($(($from, $t)),+);
         truncate_from_order!($($from),+);
     };
 
@@ -38,7 +92,324 @@ macro_rules! truncate_from_order {
 truncate_from_order!(u8, u16, u32, u64, u128);
 truncate_from_order!(i8, i16, i32, i64, i128);
 
-/// Implements `SignCast` for integer pairs, where each pair can cast into each other
+/// Implements `WidenFrom` for `(from, into)` pairs involving pointer sized integers, where core
+/// does not provide a `From` impl on every target
+macro_rules! widen_pairs {
+    ($(($from:ty, $t:ty)),+) => {
+        $(
+        impl WidenFrom<$from> for $t {
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_lossless)]
+            fn widen_from(v: $from) -> $t { v as $t }
+        }
+        )*
+    };
+}
+
+// pointer sized integers are at least 16 bits and at most 64 bits wide on all supported targets,
+// and widen both into and from the fixed width integer of the same width
+widen_pairs!((u8, usize), (u16, usize), (usize, u128));
+widen_pairs!((i8, isize), (i16, isize), (isize, i128));
+truncate_pairs!((usize, u8), (u128, usize));
+truncate_pairs!((isize, i8), (i128, isize));
+
+#[cfg(target_pointer_width = "16")]
+widen_pairs!((usize, u32), (usize, u64), (isize, i32), (isize, i64));
+#[cfg(target_pointer_width = "16")]
+truncate_pairs!((u32, usize), (u64, usize), (i32, isize), (i64, isize));
+#[cfg(target_pointer_width = "16")]
+widen_pairs!((usize, u16), (isize, i16));
+
+#[cfg(target_pointer_width = "32")]
+widen_pairs!((u32, usize), (usize, u64), (i32, isize), (isize, i64));
+#[cfg(target_pointer_width = "32")]
+truncate_pairs!((usize, u16), (u64, usize), (isize, i16), (i64, isize));
+#[cfg(target_pointer_width = "32")]
+widen_pairs!((usize, u32), (isize, i32));
+
+#[cfg(target_pointer_width = "64")]
+widen_pairs!((u32, usize), (u64, usize), (i32, isize), (i64, isize));
+#[cfg(target_pointer_width = "64")]
+truncate_pairs!((usize, u16), (usize, u32), (isize, i16), (isize, i32));
+#[cfg(target_pointer_width = "64")]
+widen_pairs!((usize, u64), (isize, i64));
+
+/// Implements `WidenSignedFrom` for `(unsigned, signed)` pairs, where the signed integer is wider
+macro_rules! widen_signed_pairs {
+    ($(($from:ty, $t:ty)),+) => {
+        $(
+        impl WidenSignedFrom<$from> for $t {
+            #[inline]
+            #[allow(clippy::cast_possible_wrap, clippy::cast_lossless)]
+            fn widen_signed_from(v: $from) -> $t { v as $t }
+        }
+        )*
+    };
+}
+
+widen_signed_pairs!((u8, i16), (u8, i32), (u8, i64), (u8, i128));
+widen_signed_pairs!((u16, i32), (u16, i64), (u16, i128));
+widen_signed_pairs!((u32, i64), (u32, i128));
+widen_signed_pairs!((u64, i128));
+
+// isize is at least 16 bits and usize is at most 64 bits wide on all supported targets
+widen_signed_pairs!((u8, isize), (usize, i128));
+
+#[cfg(target_pointer_width = "16")]
+widen_signed_pairs!((usize, i32), (usize, i64));
+
+#[cfg(target_pointer_width = "32")]
+widen_signed_pairs!((u16, isize), (usize, i64));
+
+#[cfg(target_pointer_width = "64")]
+widen_signed_pairs!((u16, isize), (u32, isize));
+
+impl WidenFrom<f32> for f64 {
+    #[inline]
+    fn widen_from(v: f32) -> f64 {
+        f64::from(v)
+    }
+}
+
+impl NarrowFrom<f64> for f32 {
+    #[inline]
+    #[allow(clippy::cast_possible_truncation)]
+    fn narrow_from(v: f64) -> f32 {
+        v as f32
+    }
+
+    #[inline]
+    fn try_narrow_from(v: f64) -> Result<f32, CastError<f64>> {
+        let narrowed = f32::narrow_from(v);
+
+        // an exact comparison is the point, infinities compare equal to themselves
+        #[allow(clippy::float_cmp)]
+        if f64::from(narrowed) == v || v.is_nan() {
+            return Ok(narrowed);
+        }
+
+        let kind = if v > f64::from(f32::MAX) {
+            CastErrorKind::Overflow
+        } else if v < f64::from(f32::MIN) {
+            CastErrorKind::Underflow
+        } else {
+            CastErrorKind::Inexact
+        };
+
+        Err(CastError::new::<f32>(v, kind))
+    }
+}
+
+/// Implements `FloatFrom` for `(int, float)` pairs, where every value of the integer is exactly
+/// representable by the float
+macro_rules! float_from_pairs {
+    ($(($from:ty, $t:ty)),+) => {
+        $(
+        impl FloatFrom<$from> for $t {
+            #[inline]
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+            fn float_from(v: $from) -> $t { v as $t }
+        }
+        )*
+    };
+}
+
+// f32 has 24 bits of precision, and f64 has 53 bits of precision
+float_from_pairs!((u8, f32), (u16, f32), (i8, f32), (i16, f32));
+float_from_pairs!(
+    (u8, f64),
+    (u16, f64),
+    (u32, f64),
+    (i8, f64),
+    (i16, f64),
+    (i32, f64)
+);
+
+#[cfg(target_pointer_width = "16")]
+float_from_pairs!((usize, f32), (isize, f32), (usize, f64), (isize, f64));
+
+#[cfg(target_pointer_width = "32")]
+float_from_pairs!((usize, f64), (isize, f64));
+
+/// Rounds floats to integral values, as `core` does not provide `round`, `floor`, etc
+trait RoundIntegral: Copy {
+    /// Rounds to an integral value using the given rounding mode, `NaN` and infinities are returned
+    /// as is
+    fn round_integral(self, mode: Rounding) -> Self;
+}
+
+/// Implements `RoundIntegral` for `(float, int)` pairs, where the int can hold any float value with
+/// a fractional part
+macro_rules! impl_round_integral {
+    ($(($f:ty, $i:ty)),+) => {
+        $(
+        impl RoundIntegral for $f {
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+            fn round_integral(self, mode: Rounding) -> $f {
+                // every float at or above this magnitude is integral
+                const INTEGRAL: $f = (1 as $i << (<$f>::MANTISSA_DIGITS - 1)) as $f;
+
+                if self.is_nan() || self.abs() >= INTEGRAL {
+                    return self;
+                }
+
+                let int = self as $i;
+                let trunc = int as $f;
+                let away = if self < 0.0 { trunc - 1.0 } else { trunc + 1.0 };
+
+                match mode {
+                    Rounding::TowardZero => trunc,
+                    Rounding::Floor if trunc > self => trunc - 1.0,
+                    Rounding::Ceil if trunc < self => trunc + 1.0,
+                    Rounding::Floor | Rounding::Ceil => trunc,
+                    Rounding::NearestEven => {
+                        // exact, as self and trunc share an exponent
+                        let frac = (self - trunc).abs();
+
+                        #[allow(clippy::float_cmp)]
+                        if frac > 0.5 || (frac == 0.5 && int % 2 != 0) {
+                            away
+                        } else {
+                            trunc
+                        }
+                    }
+                }
+            }
+        }
+        )*
+    };
+}
+
+impl_round_integral!((f32, i32), (f64, i64));
+
+/// Implements `IntFrom` for every given integer from the given float
+macro_rules! int_from_float {
+    ($f:ty; $($t:ty),+) => {
+        $(
+        impl IntFrom<$f> for $t {
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn saturating_int_from(v: $f, mode: Rounding) -> $t {
+                v.round_integral(mode) as $t
+            }
+
+            #[inline]
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::cast_precision_loss
+            )]
+            fn try_int_from(v: $f, mode: Rounding) -> Result<$t, CastError<$f>> {
+                // both bounds are powers of two, and are exact, though upper may be infinite
+                let lower = <$t>::MIN as $f;
+                let upper = (<$t>::MAX / 2 + 1) as $f * 2.0;
+
+                let rounded = v.round_integral(mode);
+
+                let kind = if v.is_nan() {
+                    CastErrorKind::NaN
+                } else if rounded >= upper {
+                    CastErrorKind::Overflow
+                } else if rounded < lower {
+                    if <$t>::MIN == 0 {
+                        CastErrorKind::NegativeToUnsigned
+                    } else {
+                        CastErrorKind::Underflow
+                    }
+                } else {
+                    return Ok(rounded as $t);
+                };
+
+                Err(CastError::new::<$t>(v, kind))
+            }
+
+            #[inline]
+            fn try_int_from_exact(v: $f) -> Result<$t, CastError<$f>> {
+                let int = <$t>::try_int_from(v, Rounding::TowardZero)?;
+
+                // an exact comparison is the point, as any rounding means a fractional part
+                #[allow(clippy::float_cmp)]
+                if v.round_integral(Rounding::TowardZero) != v {
+                    return Err(CastError::new::<$t>(v, CastErrorKind::Inexact));
+                }
+
+                Ok(int)
+            }
+        }
+        )+
+    };
+}
+
+int_from_float!(f32; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+int_from_float!(f64; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Implements `RoundedFloatFrom` for every given integer into the given float
+macro_rules! rounded_float_from {
+    ($f:ty; $($from:ty),+) => {
+        $(
+        impl RoundedFloatFrom<$from> for $f {
+            #[inline]
+            #[allow(clippy::cast_precision_loss, clippy::cast_lossless)]
+            fn rounded_float_from(v: $from) -> $f { v as $f }
+
+            #[inline]
+            fn try_float_from_exact(v: $from) -> Result<$f, CastError<$from>> {
+                let float = <$f>::rounded_float_from(v);
+
+                if <$from>::try_int_from_exact(float) == Ok(v) {
+                    Ok(float)
+                } else if float.is_infinite() {
+                    Err(CastError::new::<$f>(v, CastErrorKind::Overflow))
+                } else {
+                    Err(CastError::new::<$f>(v, CastErrorKind::Inexact))
+                }
+            }
+        }
+        )+
+    };
+}
+
+rounded_float_from!(f32; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+rounded_float_from!(f64; u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Implements `CastCheckedFrom` between every pair of the given integer types
+macro_rules! cast_checked_all {
+    ($($t:ty),+) => {
+        cast_checked_all!(@from [$($t),+] $($t),+);
+    };
+
+    (@from $targets:tt $($from:ty),+) => {
+        $(cast_checked_all!(@into $from $targets);)+
+    };
+
+    (@into $from:ty [$($t:ty),+]) => {
+        $(
+        impl CastCheckedFrom<$from> for $t {
+            #[inline]
+            fn cast_checked_from(v: $from) -> Result<$t, CastError<$from>> {
+                <$t>::try_from(v).map_err(|_| {
+                    // a failing value that is not positive must be negative
+                    let kind = if v > 0 {
+                        CastErrorKind::Overflow
+                    } else if <$t>::MIN == 0 {
+                        CastErrorKind::NegativeToUnsigned
+                    } else {
+                        CastErrorKind::Underflow
+                    };
+
+                    CastError::new::<$t>(v, kind)
+                })
+            }
+        }
+        )+
+    };
+}
+
+cast_checked_all!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Implements `SignCast` for integer pairs, where each pair can cast into each other, note that
+/// each pair must be ordered as `(unsigned, signed)`
 macro_rules! sign_cast_pairs {
     ($(($t1:ty, $t2:ty)),+) => {
         $(
@@ -46,6 +417,7 @@ macro_rules! sign_cast_pairs {
             type SignCasted = $t2;
 
             #[inline]
+            #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
             fn sign_cast(self) -> Self::SignCasted { self as $t2 }
         }
 
@@ -53,47 +425,820 @@ macro_rules! sign_cast_pairs {
             type SignCasted = $t1;
 
             #[inline]
+            #[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
             fn sign_cast(self) -> Self::SignCasted { self as $t1 }
         }
+
+        impl TrySignCast for $t1 {
+            #[inline]
+            fn try_sign_cast(self) -> Result<$t2, CastError<Self>> {
+                <$t2>::try_from(self).map_err(|_| CastError::new::<$t2>(self, CastErrorKind::Overflow))
+            }
+        }
+
+        impl TrySignCast for $t2 {
+            #[inline]
+            fn try_sign_cast(self) -> Result<$t1, CastError<Self>> {
+                <$t1>::try_from(self)
+                    .map_err(|_| CastError::new::<$t1>(self, CastErrorKind::NegativeToUnsigned))
+            }
+        }
+
+        impl SaturatingSignCast for $t1 {
+            #[inline]
+            fn saturating_sign_cast(self) -> $t2 {
+                self.min(<$t2>::MAX.sign_cast()).sign_cast()
+            }
+        }
+
+        impl SaturatingSignCast for $t2 {
+            #[inline]
+            fn saturating_sign_cast(self) -> $t1 {
+                self.max(0).sign_cast()
+            }
+        }
+
+        impl OrderedSignCast for $t1 {
+            #[inline]
+            fn ordered_sign_cast(self) -> $t2 {
+                // the signed MIN is exactly the sign bit
+                self.sign_cast() ^ <$t2>::MIN
+            }
+        }
+
+        impl OrderedSignCast for $t2 {
+            #[inline]
+            fn ordered_sign_cast(self) -> $t1 {
+                (self ^ <$t2>::MIN).sign_cast()
+            }
+        }
+
+        impl ZigZag for $t2 {
+            type ZigZagged = $t1;
+
+            #[inline]
+            fn zigzag_encode(self) -> $t1 {
+                // the arithmetic shift smears the sign bit into a mask
+                ((self << 1) ^ (self >> (<$t2>::BITS - 1))).sign_cast()
+            }
+
+            #[inline]
+            fn zigzag_decode(encoded: $t1) -> $t2 {
+                (encoded >> 1).sign_cast() ^ (encoded & 1).sign_cast().wrapping_neg()
+            }
+        }
         )*
     };
 }
 
-sign_cast_pairs!((u8, i8), (u16, i16), (u32, i32), (u64, i64), (u128, i128));
+sign_cast_pairs!(
+    (u8, i8),
+    (u16, i16),
+    (u32, i32),
+    (u64, i64),
+    (u128, i128),
+    (usize, isize)
+);
 
-/// Implements `Truncate` for each integer using the `TruncateFrom` bound
-macro_rules! impl_truncate {
+/// Implements `SignMagnitude` and `OnesComplement` for `(unsigned, signed)` integer pairs
+macro_rules! sign_repr_pairs {
+    ($(($t1:ty, $t2:ty)),+) => {
+        $(
+        impl SignMagnitude for $t2 {
+            type Encoded = $t1;
+
+            #[inline]
+            fn try_to_sign_magnitude(self) -> Result<$t1, CastError<Self>> {
+                if self == <$t2>::MIN {
+                    return Err(CastError::new::<$t1>(self, CastErrorKind::Underflow));
+                }
+
+                Ok(if self < 0 {
+                    (-self).sign_cast() | <$t2>::MIN.sign_cast()
+                } else {
+                    self.sign_cast()
+                })
+            }
+
+            #[inline]
+            fn from_sign_magnitude(encoded: $t1) -> $t2 {
+                let magnitude = (encoded & <$t2>::MAX.sign_cast()).sign_cast();
+
+                if encoded & <$t2>::MIN.sign_cast() == 0 {
+                    magnitude
+                } else {
+                    -magnitude
+                }
+            }
+        }
 
+        impl OnesComplement for $t2 {
+            type Encoded = $t1;
+
+            #[inline]
+            fn try_to_ones_complement(self) -> Result<$t1, CastError<Self>> {
+                if self == <$t2>::MIN {
+                    return Err(CastError::new::<$t1>(self, CastErrorKind::Underflow));
+                }
+
+                // the ones complement of a negative value is one less than its twos complement
+                Ok(if self < 0 { self - 1 } else { self }.sign_cast())
+            }
+
+            #[inline]
+            fn from_ones_complement(encoded: $t1) -> $t2 {
+                let value = encoded.sign_cast();
+
+                if value < 0 { value + 1 } else { value }
+            }
+        }
+        )*
+    };
+}
+
+sign_repr_pairs!(
+    (u8, i8),
+    (u16, i16),
+    (u32, i32),
+    (u64, i64),
+    (u128, i128),
+    (usize, isize)
+);
+
+/// Implements `ExtendFrom` for each integer, through the same width integers of `Int`
+macro_rules! extend_from_all {
     ($($t:ty),+) => {
         $(
-        impl Truncate for $t {
+        impl ExtendFrom for $t {
             #[inline]
-            fn truncate<T: TruncateFrom<Self>>(self) -> T {
-                T::truncate_from(self)
+            fn sign_extend_from<const N: u32>(self) -> Self::Signed {
+                const { assert!(N > 0 && N <= <$t>::BITS, "N must be within 1..=BITS") };
+
+                let shift = <$t>::BITS - N;
+
+                // the arithmetic shift right copies the sign bit of the field into the high bits
+                (self.to_signed() << shift) >> shift
+            }
+
+            #[inline]
+            fn zero_extend_from<const N: u32>(self) -> Self::Unsigned {
+                const { assert!(N > 0 && N <= <$t>::BITS, "N must be within 1..=BITS") };
+
+                self.to_unsigned() & (<Self::Unsigned>::MAX >> (<$t>::BITS - N))
             }
         }
         )*
-    }
+    };
 }
 
-impl_truncate!(u8, u16, u32, u64, u128);
-impl_truncate!(i8, i16, i32, i64, i128);
+extend_from_all!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
 
+/// Implements `WidenExtendFrom` from both integers of the `(unsigned, signed)` pair of a narrower
+/// width, into both integers of the pair of a wider width
+macro_rules! widen_extend_widths {
+    (($fu:ty, $fi:ty) => ($tu:ty, $ti:ty)) => {
+        widen_extend_widths!(@impl $fu => $tu);
+        widen_extend_widths!(@impl $fu => $ti);
+        widen_extend_widths!(@impl $fi => $tu);
+        widen_extend_widths!(@impl $fi => $ti);
+    };
 
-/// Implements `Widen` for each integer using the `WidenFrom` bound
-macro_rules! impl_widen {
+    (@impl $from:ty => $t:ty) => {
+        impl WidenExtendFrom<$from> for $t {
+            #[inline]
+            #[allow(clippy::cast_lossless, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+            fn zero_extended_from(v: $from) -> $t {
+                // casting from a narrower unsigned integer always zero extends
+                v.to_unsigned() as $t
+            }
 
-    ($($t:ty),+) => {
+            #[inline]
+            #[allow(clippy::cast_lossless, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+            fn sign_extended_from(v: $from) -> $t {
+                // casting from a narrower signed integer always sign extends
+                v.to_signed() as $t
+            }
+        }
+    };
+}
+
+/// Implements `WidenExtendFrom` for `(unsigned, signed)` integer pairs, note that the argument
+/// order to this macro is critical
+macro_rules! widen_extend_order {
+    ($t:tt, $($from:tt),+) => {
+        $(widen_extend_widths!($from => $t);)+
+        widen_extend_order!($($from),+);
+    };
+
+    ($t:tt) => {};
+}
+
+widen_extend_order!((u128, i128), (u64, i64), (u32, i32), (u16, i16), (u8, i8));
+
+// pointer sized integers are at least 16 bits and at most 64 bits wide on all supported targets
+widen_extend_widths!((u8, i8) => (usize, isize));
+widen_extend_widths!((usize, isize) => (u128, i128));
+
+#[cfg(target_pointer_width = "16")]
+widen_extend_widths!((usize, isize) => (u32, i32));
+#[cfg(target_pointer_width = "16")]
+widen_extend_widths!((usize, isize) => (u64, i64));
+
+#[cfg(target_pointer_width = "32")]
+widen_extend_widths!((u16, i16) => (usize, isize));
+#[cfg(target_pointer_width = "32")]
+widen_extend_widths!((usize, isize) => (u64, i64));
+
+#[cfg(target_pointer_width = "64")]
+widen_extend_widths!((u16, i16) => (usize, isize));
+#[cfg(target_pointer_width = "64")]
+widen_extend_widths!((u32, i32) => (usize, isize));
+
+/// Implements `Split` for `(integer, half)` pairs of unsigned integers
+macro_rules! split_pairs {
+    ($(($t:ty, $half:ty)),+) => {
         $(
-        impl Widen for $t {
+        impl Split for $t {
+            type Half = $half;
+
             #[inline]
-            fn widen<T: WidenFrom<Self>>(self) -> T {
-                T::widen_from(self)
+            fn split(self) -> ($half, $half) {
+                (self.truncate_high(), self.truncate())
+            }
+
+            #[inline]
+            fn join(high: $half, low: $half) -> $t {
+                (high.widen::<$t>() << <$half>::BITS) | low.widen::<$t>()
+            }
+        }
+        )*
+    };
+}
+
+split_pairs!((u16, u8), (u32, u16), (u64, u32), (u128, u64));
+
+/// Implements `LaneOf` for unsigned integer types, note that the argument order to this macro is
+/// critical
+macro_rules! lane_of_order {
+    ($l:ty, $($t:ty),+) => {
+        $(
+        impl LaneOf<$t> for $l {
+            #[allow(clippy::cast_possible_truncation)]
+            type Lanes = [$l; (<$t>::BITS / <$l>::BITS) as usize];
+
+            #[inline]
+            fn split_high_first(v: $t) -> Self::Lanes {
+                let mut lanes = Self::split_low_first(v);
+                lanes.reverse();
+                lanes
+            }
+
+            #[inline]
+            fn split_low_first(v: $t) -> Self::Lanes {
+                let mut v = v;
+
+                core::array::from_fn(|_| {
+                    let lane = v.truncate();
+                    v >>= <$l>::BITS;
+                    lane
+                })
+            }
+
+            #[inline]
+            fn join_high_first(lanes: Self::Lanes) -> $t {
+                lanes
+                    .into_iter()
+                    .fold(0, |acc: $t, lane| (acc << <$l>::BITS) | lane.widen::<$t>())
+            }
+
+            #[inline]
+            fn join_low_first(lanes: Self::Lanes) -> $t {
+                lanes
+                    .into_iter()
+                    .rev()
+                    .fold(0, |acc: $t, lane| (acc << <$l>::BITS) | lane.widen::<$t>())
+            }
+        }
+        )*
+        lane_of_order!($($t),+);
+    };
+
+    ($l:ty) => {};
+}
+
+lane_of_order!(u8, u16, u32, u64, u128);
+
+/// Implements `BitFieldOf` for unsigned integer types, note that the argument order to this macro
+/// is critical
+macro_rules! bit_field_of_order {
+    ($t:ty, $($f:ty),*) => {
+        bit_field_of_order!(@impl $t; $t, $($f),*);
+        bit_field_of_order!($($f),*);
+    };
+
+    ($t:ty) => {
+        bit_field_of_order!(@impl $t; $t);
+    };
+
+    (@impl $t:ty; $($f:ty),+) => {
+        $(
+        impl BitFieldOf<$t> for $f {
+            #[inline]
+            #[allow(clippy::cast_possible_truncation)]
+            fn extract_from<const LO: u32, const LEN: u32>(v: $t) -> $f {
+                const { assert_bit_field::<LO, LEN>(<$f>::BITS, <$t>::BITS) };
+
+                let mask = <$t>::MAX >> (<$t>::BITS - LEN);
+
+                // the field is masked to at most the width of the target
+                ((v >> LO) & mask) as $f
+            }
+
+            #[inline]
+            #[allow(clippy::cast_lossless)]
+            fn insert_into<const LO: u32, const LEN: u32>(self, v: $t) -> $t {
+                const { assert_bit_field::<LO, LEN>(<$f>::BITS, <$t>::BITS) };
+
+                let mask = (<$t>::MAX >> (<$t>::BITS - LEN)) << LO;
+
+                (v & !mask) | ((self as $t) << LO & mask)
+            }
+        }
+        )+
+    };
+}
+
+/// Asserts that a bit field of `LEN` bits at bit `LO` fits within both the field integer and the
+/// integer that holds it
+const fn assert_bit_field<const LO: u32, const LEN: u32>(field_bits: u32, bits: u32) {
+    assert!(LEN > 0, "LEN must be at least 1");
+    assert!(LEN <= field_bits, "LEN must fit within the field type");
+    assert!(
+        LO < bits && LEN <= bits - LO,
+        "LO + LEN must fit within the integer"
+    );
+}
+
+bit_field_of_order!(u128, u64, u32, u16, u8);
+
+/// Implements `Widening` for each integer, where the double is the `WiderInt` step
+macro_rules! widening_all {
+    ($($t:ty),+) => {
+        $(
+        impl Widening for $t {
+            type Double = <$t as WiderInt>::Wider;
+
+            #[inline]
+            fn mul_double(self, rhs: $t) -> Self::Double {
+                self.widen_step() * rhs.widen_step()
+            }
+
+            #[inline]
+            fn add_double(self, rhs: $t) -> Self::Double {
+                self.widen_step() + rhs.widen_step()
+            }
+
+            #[inline]
+            fn carrying_mul_double(self, rhs: $t, carry: $t) -> Self::Double {
+                // can never overflow, as `MAX * MAX + MAX` fits in the double width
+                self.mul_double(rhs) + carry.widen_step()
+            }
+        }
+        )*
+    };
+}
+
+widening_all!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Implements `Int` for both integers of `(unsigned, signed)` integer pairs
+macro_rules! int_pairs {
+    ($(($t1:ty, $t2:ty)),+) => {
+        $(int_pairs!(@impl $t1, $t1, $t2);)+
+        $(int_pairs!(@impl $t2, $t1, $t2);)+
+    };
+
+    (@impl $t:ty, $u:ty, $i:ty) => {
+        impl Int for $t {
+            const BITS: u32 = <$t>::BITS;
+            const IS_SIGNED: bool = <$t>::MIN != 0;
+            const MIN: $t = <$t>::MIN;
+            const MAX: $t = <$t>::MAX;
+
+            type Unsigned = $u;
+            type Signed = $i;
+
+            #[inline]
+            fn to_unsigned(self) -> $u {
+                // reinterpreting the bytes is a sign cast, or a no-op if the type is already unsigned
+                <$u>::from_ne_bytes(self.to_ne_bytes())
+            }
+
+            #[inline]
+            fn to_signed(self) -> $i {
+                <$i>::from_ne_bytes(self.to_ne_bytes())
+            }
+        }
+    };
+}
+
+int_pairs!(
+    (u8, i8),
+    (u16, i16),
+    (u32, i32),
+    (u64, i64),
+    (u128, i128),
+    (usize, isize)
+);
+
+/// Implements `WiderInt` and `NarrowerInt` for `(narrower, wider)` pairs, where the wider
+/// integer is the next integer up in width, with the same signedness
+macro_rules! int_step_pairs {
+    ($(($n:ty, $w:ty)),+) => {
+        $(
+        int_step_pairs!(@wider $n, $w);
+        int_step_pairs!(@narrower $w, $n);
+        )*
+    };
+
+    (@wider $t:ty, $w:ty) => {
+        impl WiderInt for $t {
+            type Wider = $w;
+
+            #[inline]
+            fn widen_step(self) -> $w {
+                self.widen()
+            }
+        }
+    };
+
+    (@narrower $t:ty, $n:ty) => {
+        impl NarrowerInt for $t {
+            type Narrower = $n;
+
+            #[inline]
+            fn narrow_step(self) -> $n {
+                self.truncate()
+            }
+        }
+    };
+}
+
+/// Implements `WiderInt` and `NarrowerInt` for pointer sized integers, given as
+/// `(narrower, pointer sized, wider)` triples, fixed width integers keep their own steps
+macro_rules! int_step_pointer_sized {
+    ($(($n:ty, $t:ty, $w:ty)),+) => {
+        $(
+        int_step_pairs!(@wider $t, $w);
+        int_step_pairs!(@narrower $t, $n);
+        )*
+    };
+}
+
+int_step_pairs!((u8, u16), (u16, u32), (u32, u64), (u64, u128));
+int_step_pairs!((i8, i16), (i16, i32), (i32, i64), (i64, i128));
+
+#[cfg(target_pointer_width = "16")]
+int_step_pointer_sized!((u8, usize, u32), (i8, isize, i32));
+
+#[cfg(target_pointer_width = "32")]
+int_step_pointer_sized!((u16, usize, u64), (i16, isize, i64));
+
+#[cfg(target_pointer_width = "64")]
+int_step_pointer_sized!((u32, usize, u128), (i32, isize, i128));
+
+/// Implements `BitCast` for `(float, unsigned, signed)` triples of the same width
+macro_rules! bit_cast_triples {
+    ($(($f:ty, $u:ty, $i:ty)),+) => {
+        $(
+        impl BitCast for $f {
+            type BitCasted = $u;
+
+            #[inline]
+            fn bit_cast(self) -> Self::BitCasted { self.to_bits() }
+        }
+
+        impl BitCast for $u {
+            type BitCasted = $f;
+
+            #[inline]
+            fn bit_cast(self) -> Self::BitCasted { <$f>::from_bits(self) }
+        }
+
+        impl BitCast for $i {
+            type BitCasted = $f;
+
+            #[inline]
+            fn bit_cast(self) -> Self::BitCasted { <$f>::from_bits(self.sign_cast()) }
+        }
+
+        impl OrderedBits for $f {
+            #[inline]
+            fn to_ordered_bits(self) -> $u {
+                const SIGN: $u = 1 << (<$u>::BITS - 1);
+
+                let bits = self.bit_cast();
+
+                // negative floats are ordered in reverse, and below all positive floats
+                if bits & SIGN == 0 { bits | SIGN } else { !bits }
+            }
+
+            #[inline]
+            fn from_ordered_bits(bits: $u) -> $f {
+                const SIGN: $u = 1 << (<$u>::BITS - 1);
+
+                if bits & SIGN == 0 { !bits } else { bits ^ SIGN }.bit_cast()
+            }
+        }
+        )*
+    };
+}
+
+bit_cast_triples!((f32, u32, i32), (f64, u64, i64));
+
+/// Implements `Truncate` for each integer using the `TruncateFrom` bound
+macro_rules! impl_truncate {
+
+    ($($t:ty),+) => {
+        $(
+        impl Truncate for $t {
+            #[inline]
+            fn truncate<T: TruncateFrom<Self>>(self) -> T {
+                T::truncate_from(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_truncate!(u8, u16, u32, u64, u128, usize);
+impl_truncate!(i8, i16, i32, i64, i128, isize);
+
+/// Implements `TryTruncate` for each integer using the `TryTruncateFrom` bound
+macro_rules! impl_try_truncate {
+
+    ($($t:ty),+) => {
+        $(
+        impl TryTruncate for $t {
+            #[inline]
+            fn try_truncate<T: TryTruncateFrom<Self>>(self) -> Result<T, CastError<Self>> {
+                T::try_truncate_from(self)
+            }
+
+            #[inline]
+            fn overflowing_truncate<T: TryTruncateFrom<Self>>(self) -> (T, bool) {
+                T::overflowing_truncate_from(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_try_truncate!(u8, u16, u32, u64, u128, usize);
+impl_try_truncate!(i8, i16, i32, i64, i128, isize);
+
+/// Implements `SaturatingTruncate` for each integer using the `SaturatingTruncateFrom` bound
+macro_rules! impl_saturating_truncate {
+
+    ($($t:ty),+) => {
+        $(
+        impl SaturatingTruncate for $t {
+            #[inline]
+            fn saturating_truncate<T: SaturatingTruncateFrom<Self>>(self) -> T {
+                T::saturating_truncate_from(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_saturating_truncate!(u8, u16, u32, u64, u128, usize);
+impl_saturating_truncate!(i8, i16, i32, i64, i128, isize);
+
+/// Implements `TruncateHigh` for each integer using the `TruncateHighFrom` bound
+macro_rules! impl_truncate_high {
+
+    ($($t:ty),+) => {
+        $(
+        impl TruncateHigh for $t {
+            #[inline]
+            fn truncate_high<T: TruncateHighFrom<Self>>(self) -> T {
+                T::truncate_high_from(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_truncate_high!(u8, u16, u32, u64, u128, usize);
+impl_truncate_high!(i8, i16, i32, i64, i128, isize);
+
+/// Implements `SplitLanes` for each integer using the `LaneOf` bound
+macro_rules! impl_split_lanes {
+
+    ($($t:ty),+) => {
+        $(
+        impl SplitLanes for $t {
+            #[inline]
+            fn to_lanes_high_first<T: LaneOf<Self>>(self) -> T::Lanes {
+                T::split_high_first(self)
+            }
+
+            #[inline]
+            fn to_lanes_low_first<T: LaneOf<Self>>(self) -> T::Lanes {
+                T::split_low_first(self)
+            }
+
+            #[inline]
+            fn from_lanes_high_first<T: LaneOf<Self>>(lanes: T::Lanes) -> Self {
+                T::join_high_first(lanes)
+            }
+
+            #[inline]
+            fn from_lanes_low_first<T: LaneOf<Self>>(lanes: T::Lanes) -> Self {
+                T::join_low_first(lanes)
+            }
+        }
+        )*
+    }
+}
+
+impl_split_lanes!(u16, u32, u64, u128);
+
+/// Implements `BitField` for each integer using the `BitFieldOf` bound
+macro_rules! impl_bit_field {
+
+    ($($t:ty),+) => {
+        $(
+        impl BitField for $t {
+            #[inline]
+            fn extract_field<const LO: u32, const LEN: u32, T: BitFieldOf<Self>>(self) -> T {
+                T::extract_from::<LO, LEN>(self)
+            }
+
+            #[inline]
+            fn insert_field<const LO: u32, const LEN: u32>(self, field: impl BitFieldOf<Self>) -> Self {
+                field.insert_into::<LO, LEN>(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_bit_field!(u8, u16, u32, u64, u128);
+
+/// Implements `Widen` for each integer using the `WidenFrom` bound
+macro_rules! impl_widen {
+
+    ($($t:ty),+) => {
+        $(
+        impl Widen for $t {
+            #[inline]
+            fn widen<T: WidenFrom<Self>>(self) -> T {
+                T::widen_from(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_widen!(u8, u16, u32, u64, u128, usize);
+impl_widen!(i8, i16, i32, i64, i128, isize);
+impl_widen!(f32);
+
+/// Implements `ToFloat` for each integer using the `FloatFrom` bound
+macro_rules! impl_to_float {
+
+    ($($t:ty),+) => {
+        $(
+        impl ToFloat for $t {
+            #[inline]
+            fn to_float<T: FloatFrom<Self>>(self) -> T {
+                T::float_from(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_to_float!(u8, u16, u32, u64, u128, usize);
+impl_to_float!(i8, i16, i32, i64, i128, isize);
+
+/// Implements `ToFloatRounded` for each integer using the `RoundedFloatFrom` bound
+macro_rules! impl_to_float_rounded {
+
+    ($($t:ty),+) => {
+        $(
+        impl ToFloatRounded for $t {
+            #[inline]
+            fn to_float_rounded<T: RoundedFloatFrom<Self>>(self) -> T {
+                T::rounded_float_from(self)
+            }
+
+            #[inline]
+            fn try_to_float_exact<T: RoundedFloatFrom<Self>>(self) -> Result<T, CastError<Self>> {
+                T::try_float_from_exact(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_to_float_rounded!(u8, u16, u32, u64, u128, usize);
+impl_to_float_rounded!(i8, i16, i32, i64, i128, isize);
+
+/// Implements `ToInt` for each float using the `IntFrom` bound
+macro_rules! impl_to_int {
+
+    ($($t:ty),+) => {
+        $(
+        impl ToInt for $t {
+            #[inline]
+            fn saturating_to_int<T: IntFrom<Self>>(self, mode: Rounding) -> T {
+                T::saturating_int_from(self, mode)
+            }
+
+            #[inline]
+            fn try_to_int<T: IntFrom<Self>>(self, mode: Rounding) -> Result<T, CastError<Self>> {
+                T::try_int_from(self, mode)
+            }
+
+            #[inline]
+            fn try_to_int_exact<T: IntFrom<Self>>(self) -> Result<T, CastError<Self>> {
+                T::try_int_from_exact(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_to_int!(f32, f64);
+
+impl Narrow for f64 {
+    #[inline]
+    fn narrow<T: NarrowFrom<Self>>(self) -> T {
+        T::narrow_from(self)
+    }
+
+    #[inline]
+    fn try_narrow<T: NarrowFrom<Self>>(self) -> Result<T, CastError<Self>> {
+        T::try_narrow_from(self)
+    }
+}
+
+/// Implements `WidenSigned` for each unsigned integer using the `WidenSignedFrom` bound
+macro_rules! impl_widen_signed {
+
+    ($($t:ty),+) => {
+        $(
+        impl WidenSigned for $t {
+            #[inline]
+            fn widen_signed<T: WidenSignedFrom<Self>>(self) -> T {
+                T::widen_signed_from(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_widen_signed!(u8, u16, u32, u64, usize);
+
+/// Implements `WidenExtend` for each integer using the `WidenExtendFrom` bound
+macro_rules! impl_widen_extend {
+
+    ($($t:ty),+) => {
+        $(
+        impl WidenExtend for $t {
+            #[inline]
+            fn zero_extend<T: WidenExtendFrom<Self>>(self) -> T {
+                T::zero_extended_from(self)
+            }
+
+            #[inline]
+            fn sign_extend<T: WidenExtendFrom<Self>>(self) -> T {
+                T::sign_extended_from(self)
+            }
+        }
+        )*
+    }
+}
+
+impl_widen_extend!(u8, u16, u32, u64, u128, usize);
+impl_widen_extend!(i8, i16, i32, i64, i128, isize);
+
+/// Implements `CastChecked` for each integer using the `CastCheckedFrom` bound
+macro_rules! impl_cast_checked {
+
+    ($($t:ty),+) => {
+        $(
+        impl CastChecked for $t {
+            #[inline]
+            fn cast_checked<T: CastCheckedFrom<Self>>(self) -> Result<T, CastError<Self>> {
+                T::cast_checked_from(self)
             }
         }
         )*
     }
 }
 
-impl_widen!(u8, u16, u32, u64, u128);
-impl_widen!(i8, i16, i32, i64, i128);
+impl_cast_checked!(u8, u16, u32, u64, u128, usize);
+impl_cast_checked!(i8, i16, i32, i64, i128, isize);
@@ -0,0 +1,132 @@
+//! The error type returned by the checked casting traits
+
+use core::{any::type_name, fmt};
+
+/// The reason a checked cast failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum CastErrorKind {
+    /// The value was larger than the maximum value of the target type
+    Overflow,
+    /// The value was smaller than the minimum value of the target type
+    Underflow,
+    /// The value was negative, and the target type is unsigned
+    NegativeToUnsigned,
+    /// The value is within range of the target type, but cannot be represented exactly
+    Inexact,
+    /// The value was a float `NaN`, which has no integer representation
+    NaN,
+}
+
+impl CastErrorKind {
+    /// A short human readable description of this kind of failure
+    const fn description(self) -> &'static str {
+        match self {
+            Self::Overflow => "overflows",
+            Self::Underflow => "underflows",
+            Self::NegativeToUnsigned => "is negative and cannot be represented",
+            Self::Inexact => "cannot be represented exactly",
+            Self::NaN => "is not a number",
+        }
+    }
+}
+
+/// The error returned when a checked cast cannot represent a value in its target type.
+///
+/// Holds the original value, the names of the source and target types, and the
+/// [`CastErrorKind`] describing why the cast failed. This type does not allocate, so it is usable
+/// in `no_std` environments, and implements [`core::error::Error`] so it works with `?`.
+///
+/// # Examples
+/// ```
+/// # use explicit_cast::{CastErrorKind, TryTruncate};
+/// let err = 300u16.try_truncate::<u8>().unwrap_err();
+///
+/// assert_eq!(err.kind(), CastErrorKind::Overflow);
+/// assert_eq!(err.value(), 300);
+/// assert_eq!(err.source_type(), "u16");
+/// assert_eq!(err.target_type(), "u8");
+/// assert_eq!(err.to_string(), "u16 value 300 overflows when cast to u8");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CastError<T> {
+    /// The value that failed to cast
+    value: T,
+    /// The name of the type being cast into
+    target: &'static str,
+    /// Why the cast failed
+    kind: CastErrorKind,
+}
+
+impl<T> CastError<T> {
+    /// Creates a new error for a value that failed to cast into `U`
+    pub(crate) fn new<U>(value: T, kind: CastErrorKind) -> Self {
+        Self {
+            value,
+            target: type_name::<U>(),
+            kind,
+        }
+    }
+
+    /// Returns why the cast failed
+    #[must_use]
+    pub const fn kind(&self) -> CastErrorKind {
+        self.kind
+    }
+
+    /// Returns the name of the type the value was cast from
+    #[must_use]
+    pub fn source_type(&self) -> &'static str {
+        type_name::<T>()
+    }
+
+    /// Returns the name of the type the value failed to cast into
+    #[must_use]
+    pub const fn target_type(&self) -> &'static str {
+        self.target
+    }
+
+    /// Returns the original value that failed to cast
+    #[must_use]
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+}
+
+impl<T: Copy> CastError<T> {
+    /// Returns a copy of the original value that failed to cast
+    #[must_use]
+    pub const fn value(&self) -> T {
+        self.value
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for CastError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} value {} {} when cast to {}",
+            self.source_type(),
+            self.value,
+            self.kind.description(),
+            self.target
+        )
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> core::error::Error for CastError<T> {}
+
+#[cfg(feature = "defmt")]
+impl<T: defmt::Format> defmt::Format for CastError<T> {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(
+            f,
+            "{=str} value {} {=str} when cast to {=str}",
+            self.source_type(),
+            self.value,
+            self.kind.description(),
+            self.target
+        );
+    }
+}
@@ -7,7 +7,30 @@
 //!
 //! assert_eq!(5u8.widen::<u16>().sign_cast().widen::<i32>().truncate::<i8>(), 5i8);
 //! ```
-//! 
+//!
+//! # Pointer sized integers
+//! [`usize`] and [`isize`] are supported, but which widths they can be widened from or truncated
+//! into depends on `target_pointer_width`. `u16` always widens into `usize`, `u32` only widens
+//! into `usize` on 32 and 64 bit targets, and `usize` always truncates into `u8`. A pointer sized
+//! integer also widens both into and from the fixed width integer of the same width, so on a 64
+//! bit target `usize` and `u64` widen into each other:
+//! ```
+//! use explicit_cast::prelude::*;
+//!
+//! let len: usize = 300u16.widen();
+//! assert_eq!(len.truncate::<u8>(), 44);
+//! assert_eq!(len.sign_cast(), 300isize);
+//! ```
+//!
+//! # Floats
+//! [`f32`] widens into [`f64`] with [`Widen`], and [`f64`] narrows into [`f32`] with [`Narrow`],
+//! which has a checked companion that only succeeds if the value round trips exactly.
+//! Integers convert into floats with [`ToFloat`], only where every value is exactly
+//! representable, or with [`ToFloatRounded`] where they may round, and floats convert into
+//! integers with [`ToInt`], which always names its [`Rounding`] mode. Floats and same width
+//! integers can also reinterpret each others bits with [`BitCast`], and floats can be converted
+//! to integer keys that preserve their total order with [`OrderedBits`].
+//!
 //! # Stability
 //! This crate is 1.0 as in being **stable and or finished**, as there is no other functionality to be had than
 //! allowing explicit casting of integers. As such, a prelude has been included that imports [`Widen`],
@@ -15,8 +38,15 @@
 //! new traits** will be added to the prelude, without a 2.0 release, that theoretically should never
 //! happen.
 //!
-//! Documentation updates may be published under a 1.0.X patch release, but no new functionality is
-//! planned.
+//! Additional functionality, such as checked and saturating companions to these traits (i/e
+//! [`TryTruncate`] and [`SaturatingSignCast`]), or lossless cross sign widening with
+//! [`WidenSigned`], lives in separate traits outside of the prelude, and must be imported
+//! explicitly. The [`varint`] module provides LEB128 encoding and decoding, which reports values
+//! that do not fit instead of truncating them.
+//!
+//! # Feature flags
+//! - `defmt`: implements `defmt::Format` for [`CastError`], [`CastErrorKind`], and
+//!   [`VarintError`](varint::VarintError)
 
 #![no_std]
 #![forbid(unsafe_code)]
@@ -26,6 +56,10 @@
 #![warn(missing_docs, clippy::missing_docs_in_private_items)]
 
 mod codegen;
+mod error;
+pub mod varint;
+
+pub use error::{CastError, CastErrorKind};
 
 /// Sealed module
 mod sealed {
@@ -39,8 +73,9 @@ mod sealed {
         }
     }
 
-    sealed!(u8, u16, u32, u64, u128);
-    sealed!(i8, i16, i32, i64, i128);
+    sealed!(u8, u16, u32, u64, u128, usize);
+    sealed!(i8, i16, i32, i64, i128, isize);
+    sealed!(f32, f64);
 }
 
 use sealed::Sealed;
@@ -49,11 +84,146 @@ use sealed::Sealed;
 ///
 /// This may be useful to import yourself if you wish to use it in API's, but it is only a
 /// byproduct of this crate.
+///
+/// Pointer sized integers are the exception to always widening from a smaller integer, see
+/// [`Widen`].
 pub trait WidenFrom<T>: Sealed {
-    /// Widens into [`Self`] from a smaller integer
+    /// Widens into [`Self`] from a smaller integer, or a pointer sized integer of the same width
     fn widen_from(v: T) -> Self;
 }
 
+/// The inner trait of [`WidenSigned`] that allows it to have a generic function signature.
+///
+/// This is implemented for every unsigned integer that fits losslessly in a wider signed integer.
+pub trait WidenSignedFrom<T>: Sealed {
+    /// Widens into [`Self`] from a smaller unsigned integer
+    fn widen_signed_from(v: T) -> Self;
+}
+
+/// The inner trait of [`Narrow`] that allows it to have a generic function signature.
+///
+/// This may be useful to import yourself if you wish to use it in API's, but it is only a
+/// byproduct of this crate.
+pub trait NarrowFrom<T>: Sealed + Sized {
+    /// Narrows into [`Self`] from a larger float, rounding to the nearest representable value
+    fn narrow_from(v: T) -> Self;
+
+    /// Narrows into [`Self`] from a larger float, failing if the value would not round trip
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] holding the original value if it is not exactly representable in
+    /// [`Self`]
+    fn try_narrow_from(v: T) -> Result<Self, CastError<T>>;
+}
+
+/// The inner trait of [`ToFloat`] that allows it to have a generic function signature.
+///
+/// This is only implemented for integer and float pairs where every value of the integer is
+/// exactly representable by the float.
+pub trait FloatFrom<T>: Sealed {
+    /// Converts into [`Self`] from an integer, without rounding
+    fn float_from(v: T) -> Self;
+}
+
+/// The inner trait of [`ToFloatRounded`] that allows it to have a generic function signature.
+///
+/// This is implemented for every integer into both [`f32`] and [`f64`].
+pub trait RoundedFloatFrom<T>: Sealed + Sized {
+    /// Converts into [`Self`] from an integer, rounding to the nearest representable value with
+    /// ties to even
+    fn rounded_float_from(v: T) -> Self;
+
+    /// Converts into [`Self`] from an integer, failing if it is not exactly representable
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] holding the original value if it is not exactly representable in
+    /// [`Self`]
+    fn try_float_from_exact(v: T) -> Result<Self, CastError<T>>;
+}
+
+/// The rounding mode used when converting a float to an integer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rounding {
+    /// Rounds toward zero, discarding the fractional part, this is what `as` casting does
+    TowardZero,
+    /// Rounds to the nearest integer, rounding ties to the nearest even integer
+    NearestEven,
+    /// Rounds toward negative infinity
+    Floor,
+    /// Rounds toward positive infinity
+    Ceil,
+}
+
+/// The inner trait of [`ToInt`] that allows it to have a generic function signature.
+///
+/// This is implemented for every integer from both [`f32`] and [`f64`].
+pub trait IntFrom<T>: Sealed + Sized {
+    /// Converts into [`Self`] from a float using the given rounding mode, saturating at the bounds
+    /// of [`Self`] and converting `NaN` to `0`
+    fn saturating_int_from(v: T, mode: Rounding) -> Self;
+
+    /// Converts into [`Self`] from a float using the given rounding mode, failing if the rounded
+    /// value is not representable
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] holding the original value if it is `NaN` or out of range
+    fn try_int_from(v: T, mode: Rounding) -> Result<Self, CastError<T>>;
+
+    /// Converts into [`Self`] from a float, failing if it is not exactly representable
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] holding the original value if it is `NaN`, out of range, or has a
+    /// fractional part
+    fn try_int_from_exact(v: T) -> Result<Self, CastError<T>>;
+}
+
+/// The inner trait of [`WidenExtend`] that allows it to have a generic function signature.
+///
+/// This is implemented for every pair of integers where [`Self`] is strictly wider than `T`,
+/// regardless of signedness.
+pub trait WidenExtendFrom<T>: Sealed {
+    /// Widens into [`Self`] from a smaller integer, filling the new high bits with zeroes
+    fn zero_extended_from(v: T) -> Self;
+
+    /// Widens into [`Self`] from a smaller integer, filling the new high bits with copies of its
+    /// most significant bit
+    fn sign_extended_from(v: T) -> Self;
+}
+
+/// The inner trait of [`SplitLanes`] that allows it to have a generic function signature.
+///
+/// This is implemented for every unsigned integer that is narrower than `T`, where the
+/// [`Lanes`](LaneOf::Lanes) array holds exactly as many lanes as fit in `T`.
+pub trait LaneOf<T>: Sealed {
+    /// The array of lanes that `T` splits into, i/e `[u8; 4]` for `u32`
+    type Lanes;
+
+    /// Splits an integer into lanes, ordered from most significant to least significant
+    fn split_high_first(v: T) -> Self::Lanes;
+
+    /// Splits an integer into lanes, ordered from least significant to most significant
+    fn split_low_first(v: T) -> Self::Lanes;
+
+    /// Joins lanes ordered from most significant to least significant into an integer
+    fn join_high_first(lanes: Self::Lanes) -> T;
+
+    /// Joins lanes ordered from least significant to most significant into an integer
+    fn join_low_first(lanes: Self::Lanes) -> T;
+}
+
+/// The inner trait of [`BitField`] that allows it to have a generic function signature.
+///
+/// This is implemented for every unsigned integer that is no wider than `T`, and is the field
+/// type of [`extract_field`](BitField::extract_field) and
+/// [`insert_field`](BitField::insert_field).
+pub trait BitFieldOf<T>: Sealed + Sized {
+    /// Extracts the `LEN` bit wide field starting at bit `LO` of `v`
+    fn extract_from<const LO: u32, const LEN: u32>(v: T) -> Self;
+
+    /// Inserts the low `LEN` bits of this field into `v` starting at bit `LO`
+    fn insert_into<const LO: u32, const LEN: u32>(self, v: T) -> T;
+}
+
 /// The inner trait of [`Truncate`] that allows it to have a generic function signature.
 ///
 /// This may be useful to import yourself if you wish to use it in API's, but it is only a
@@ -63,6 +233,48 @@ pub trait TruncateFrom<T>: Sealed {
     fn truncate_from(v: T) -> Self;
 }
 
+/// The inner trait of [`TryTruncate`] that allows it to have a generic function signature.
+///
+/// This is implemented for exactly the same type pairs as [`TruncateFrom`].
+pub trait TryTruncateFrom<T>: TruncateFrom<T> + Sized {
+    /// Truncates into [`Self`] from a larger integer, failing if any significant bits would be lost
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] holding the original value if it does not fit in [`Self`]
+    fn try_truncate_from(v: T) -> Result<Self, CastError<T>>;
+
+    /// Truncates into [`Self`] from a larger integer, returning whether significant bits were lost
+    fn overflowing_truncate_from(v: T) -> (Self, bool);
+}
+
+/// The inner trait of [`SaturatingTruncate`] that allows it to have a generic function signature.
+///
+/// This is implemented for exactly the same type pairs as [`TruncateFrom`].
+pub trait SaturatingTruncateFrom<T>: TruncateFrom<T> {
+    /// Truncates into [`Self`] from a larger integer, clamping to [`Self`]'s bounds if it does not
+    /// fit
+    fn saturating_truncate_from(v: T) -> Self;
+}
+
+/// The inner trait of [`CastChecked`] that allows it to have a generic function signature.
+///
+/// This is implemented between every pair of integers supported by this crate.
+pub trait CastCheckedFrom<T>: Sealed + Sized {
+    /// Casts into [`Self`] from any integer, failing if the value is not representable
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] holding the original value if it is not representable in [`Self`]
+    fn cast_checked_from(v: T) -> Result<Self, CastError<T>>;
+}
+
+/// The inner trait of [`TruncateHigh`] that allows it to have a generic function signature.
+///
+/// This is implemented for exactly the same type pairs as [`TruncateFrom`].
+pub trait TruncateHighFrom<T>: TruncateFrom<T> {
+    /// Truncates into [`Self`] from a larger integer, keeping its most significant bits
+    fn truncate_high_from(v: T) -> Self;
+}
+
 /// Trait to sign cast an integer to/from signed/unsigned
 ///
 /// This is better than `as` casting because:
@@ -89,6 +301,279 @@ pub trait SignCast: Sealed {
     fn sign_cast(self) -> Self::SignCasted;
 }
 
+/// Trait to sign cast an integer to/from signed/unsigned, while checking that the numeric value
+/// does not change.
+///
+/// This supports the same type pairs as [`SignCast`].
+pub trait TrySignCast: SignCast + Sized {
+    /// Casts an unsigned integer to a signed integer, or a signed integer to an unsigned integer,
+    /// failing if the value is not representable in the target type
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] of kind [`CastErrorKind::NegativeToUnsigned`] if a signed value is
+    /// negative, or [`CastErrorKind::Overflow`] if an unsigned value is larger than the signed
+    /// type's maximum
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{CastErrorKind, TrySignCast};
+    /// assert_eq!(127u8.try_sign_cast(), Ok(127i8));
+    /// assert_eq!(200u8.try_sign_cast().unwrap_err().kind(), CastErrorKind::Overflow);
+    /// assert_eq!((-1i8).try_sign_cast().unwrap_err().kind(), CastErrorKind::NegativeToUnsigned);
+    /// ```
+    fn try_sign_cast(self) -> Result<Self::SignCasted, CastError<Self>>;
+}
+
+/// Trait to sign cast an integer to/from signed/unsigned, clamping values that are not
+/// representable in the target type.
+///
+/// This supports the same type pairs as [`SignCast`].
+pub trait SaturatingSignCast: SignCast {
+    /// Casts an unsigned integer to a signed integer, or a signed integer to an unsigned integer,
+    /// clamping negative values to `0` and values above the signed maximum to `MAX`
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::SaturatingSignCast;
+    /// assert_eq!((-5i32).saturating_sign_cast(), 0u32);
+    /// assert_eq!(u32::MAX.saturating_sign_cast(), i32::MAX);
+    /// assert_eq!(42u32.saturating_sign_cast(), 42i32);
+    /// ```
+    fn saturating_sign_cast(self) -> Self::SignCasted;
+}
+
+/// Trait to reinterpret the bits of a float as a same width integer, or the bits of an integer as
+/// a same width float.
+///
+/// This is better than mixing [`to_bits`](f32::to_bits) with `as` casts because:
+/// - It is explicitly only reinterpreting bits, and will not change width
+/// - It is method chainable with the other traits of this crate
+///
+/// Floats bit cast into the unsigned integer of the same width, and both the signed and unsigned
+/// integers of the same width bit cast into a float.
+pub trait BitCast: Sealed {
+    /// The target type after casting bits
+    type BitCasted;
+
+    /// Reinterprets the bits of a float as an integer, or an integer as a float.
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{BitCast, Widen};
+    /// assert_eq!(1.0f32.bit_cast().widen::<u128>(), 0x3f80_0000);
+    /// assert_eq!(0x3ff0_0000_0000_0000u64.bit_cast(), 1.0f64);
+    /// assert_eq!((-1i32).bit_cast().is_nan(), true);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::BitCast;
+    /// let bits: u64 = 0f32.bit_cast();
+    /// ```
+    fn bit_cast(self) -> Self::BitCasted;
+}
+
+/// Trait to convert a float to and from an unsigned integer key, whose natural ordering matches
+/// the IEEE 754 total order of the float.
+///
+/// This is useful for radix sorting floats, or storing them in byte comparable database keys, and
+/// orders floats exactly like [`total_cmp`](f64::total_cmp).
+pub trait OrderedBits: BitCast + Sized {
+    /// Converts a float to an unsigned integer key that orders like the float
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::OrderedBits;
+    /// assert!((-1.0f32).to_ordered_bits() < (-0.0f32).to_ordered_bits());
+    /// assert!((-0.0f32).to_ordered_bits() < 0.0f32.to_ordered_bits());
+    /// assert!(0.0f32.to_ordered_bits() < f32::INFINITY.to_ordered_bits());
+    /// ```
+    fn to_ordered_bits(self) -> Self::BitCasted;
+
+    /// Converts an unsigned integer key produced by [`to_ordered_bits`](OrderedBits::to_ordered_bits)
+    /// back into a float
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::OrderedBits;
+    /// let key = (-2.5f64).to_ordered_bits();
+    /// assert_eq!(f64::from_ordered_bits(key), -2.5);
+    /// ```
+    fn from_ordered_bits(bits: Self::BitCasted) -> Self;
+}
+
+/// Trait to sign cast an integer to/from signed/unsigned while preserving its ordering, by
+/// flipping the sign bit (also known as offset binary).
+///
+/// Unlike [`SignCast`], this maps `MIN..=MAX` of the signed integer monotonically onto
+/// `0..=MAX` of the unsigned integer, and back. This is useful for radix sorting, offset binary
+/// ADC samples, and byte comparable keys.
+///
+/// This supports the same type pairs as [`SignCast`].
+pub trait OrderedSignCast: SignCast {
+    /// Casts a signed integer to an unsigned integer, or an unsigned integer to a signed integer,
+    /// such that the ordering of values is preserved
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::OrderedSignCast;
+    /// assert_eq!(i32::MIN.ordered_sign_cast(), 0u32);
+    /// assert_eq!((-1i32).ordered_sign_cast(), 0x7fff_ffffu32);
+    /// assert_eq!(0i32.ordered_sign_cast(), 0x8000_0000u32);
+    /// assert_eq!(u32::MAX.ordered_sign_cast(), i32::MAX);
+    /// ```
+    fn ordered_sign_cast(self) -> Self::SignCasted;
+}
+
+/// Trait to zigzag encode a signed integer into the unsigned integer of the same width, and
+/// decode it back, as used by protobuf and other varint formats.
+///
+/// Zigzag encoding interleaves negative and positive values, so that integers with a small
+/// magnitude produce small codes, i/e `0, -1, 1, -2` encode as `0, 1, 2, 3`.
+///
+/// This is implemented for every signed integer that supports [`SignCast`].
+pub trait ZigZag: Sealed {
+    /// The unsigned integer that this integer is encoded as
+    type ZigZagged;
+
+    /// Zigzag encodes a signed integer
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::ZigZag;
+    /// assert_eq!(0i32.zigzag_encode(), 0u32);
+    /// assert_eq!((-1i32).zigzag_encode(), 1u32);
+    /// assert_eq!(1i32.zigzag_encode(), 2u32);
+    /// assert_eq!(i32::MIN.zigzag_encode(), u32::MAX);
+    /// ```
+    fn zigzag_encode(self) -> Self::ZigZagged;
+
+    /// Decodes a zigzag encoded integer
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::ZigZag;
+    /// assert_eq!(i64::zigzag_decode(3), -2);
+    /// assert_eq!(i64::zigzag_decode(u64::MAX - 1), i64::MAX);
+    /// ```
+    fn zigzag_decode(encoded: Self::ZigZagged) -> Self;
+}
+
+/// Trait to convert a twos complement signed integer to and from sign-magnitude representation,
+/// stored in the unsigned integer of the same width.
+///
+/// In sign-magnitude, the most significant bit is the sign, and the remaining bits are the
+/// magnitude, so `MIN` of the signed integer cannot be represented, and there is a negative zero.
+///
+/// This is implemented for every signed integer that supports [`SignCast`].
+pub trait SignMagnitude: Sealed + Sized {
+    /// The unsigned integer that holds the sign-magnitude representation
+    type Encoded;
+
+    /// Converts a signed integer to sign-magnitude
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] of kind [`CastErrorKind::Underflow`] if the value is `MIN`, which
+    /// has no sign-magnitude representation
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{CastErrorKind, SignMagnitude};
+    /// assert_eq!(5i8.try_to_sign_magnitude(), Ok(0x05u8));
+    /// assert_eq!((-5i8).try_to_sign_magnitude(), Ok(0x85u8));
+    /// assert_eq!(i8::MIN.try_to_sign_magnitude().unwrap_err().kind(), CastErrorKind::Underflow);
+    /// ```
+    fn try_to_sign_magnitude(self) -> Result<Self::Encoded, CastError<Self>>;
+
+    /// Converts from sign-magnitude to a signed integer, negative zero converts to `0`
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::SignMagnitude;
+    /// assert_eq!(i8::from_sign_magnitude(0x85), -5);
+    /// assert_eq!(i8::from_sign_magnitude(0x80), 0);
+    /// assert_eq!(i8::from_sign_magnitude(0xff), -127);
+    /// ```
+    fn from_sign_magnitude(encoded: Self::Encoded) -> Self;
+}
+
+/// Trait to convert a twos complement signed integer to and from ones' complement
+/// representation, stored in the unsigned integer of the same width.
+///
+/// In ones' complement, a negative value is the bitwise not of its magnitude, so `MIN` of the
+/// signed integer cannot be represented, and there is a negative zero.
+///
+/// This is implemented for every signed integer that supports [`SignCast`].
+pub trait OnesComplement: Sealed + Sized {
+    /// The unsigned integer that holds the ones' complement representation
+    type Encoded;
+
+    /// Converts a signed integer to ones' complement
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] of kind [`CastErrorKind::Underflow`] if the value is `MIN`, which
+    /// has no ones' complement representation
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{CastErrorKind, OnesComplement};
+    /// assert_eq!(5i8.try_to_ones_complement(), Ok(0x05u8));
+    /// assert_eq!((-5i8).try_to_ones_complement(), Ok(0xfau8));
+    /// assert_eq!(i8::MIN.try_to_ones_complement().unwrap_err().kind(), CastErrorKind::Underflow);
+    /// ```
+    fn try_to_ones_complement(self) -> Result<Self::Encoded, CastError<Self>>;
+
+    /// Converts from ones' complement to a signed integer, negative zero converts to `0`
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::OnesComplement;
+    /// assert_eq!(i8::from_ones_complement(0xfa), -5);
+    /// assert_eq!(i8::from_ones_complement(0xff), 0);
+    /// assert_eq!(i8::from_ones_complement(0x80), -127);
+    /// ```
+    fn from_ones_complement(encoded: Self::Encoded) -> Self;
+}
+
+/// Trait to treat the low `N` bits of an integer as an `N` bit wide field, and extend it to the
+/// full width of the integer.
+///
+/// This is useful for emulators and protocol decoders, where i/e the low 12 bits of a `u16` hold
+/// a signed field. The result can be widened further with [`Widen`].
+///
+/// `N` must be within `1..=BITS` of the integer, otherwise it will *not* compile. Results are
+/// returned as the [`Int::Unsigned`] or [`Int::Signed`] integer of the same width.
+pub trait ExtendFrom: Int {
+    /// Sign extends the low `N` bits of this integer, ignoring all higher bits
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{ExtendFrom, Widen};
+    /// assert_eq!(0xf800u16.sign_extend_from::<12>().widen::<i32>(), -2048);
+    /// assert_eq!(0x07ffu16.sign_extend_from::<12>(), 2047i16);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::ExtendFrom;
+    /// let val = 0u16.sign_extend_from::<17>();
+    /// ```
+    fn sign_extend_from<const N: u32>(self) -> Self::Signed;
+
+    /// Zero extends the low `N` bits of this integer, clearing all higher bits
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{ExtendFrom, Widen};
+    /// assert_eq!((-1i16).zero_extend_from::<12>().widen::<u32>(), 0x0fff);
+    /// assert_eq!(0xf800u16.zero_extend_from::<12>(), 0x0800u16);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::ExtendFrom;
+    /// let val = 0u8.zero_extend_from::<0>();
+    /// ```
+    fn zero_extend_from<const N: u32>(self) -> Self::Unsigned;
+}
+
 /// Trait to truncate an integer from a larger size.
 ///
 /// This is better than `as` casting because:
@@ -115,11 +600,225 @@ pub trait Truncate: Sealed + Sized {
     fn truncate<T: TruncateFrom<Self>>(self) -> T;
 }
 
+/// Trait to truncate an integer from a larger size, while checking that no significant bits are
+/// lost.
+///
+/// This supports the same type pairs as [`Truncate`], so `u16` to `i8` will *not* compile.
+pub trait TryTruncate: Truncate {
+    /// Truncates an integer to a smaller integer, failing if the value does not fit
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] holding the original value if it does not fit in `T`
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::TryTruncate;
+    /// assert_eq!(255u16.try_truncate::<u8>(), Ok(255));
+    /// assert!(256u16.try_truncate::<u8>().is_err());
+    /// assert!((-129i16).try_truncate::<i8>().is_err());
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::TryTruncate;
+    /// let val = 0u16.try_truncate::<i8>();
+    /// ```
+    fn try_truncate<T: TryTruncateFrom<Self>>(self) -> Result<T, CastError<Self>>;
+
+    /// Truncates an integer to a smaller integer, returning the wrapped value along with whether
+    /// significant bits were lost, like the `overflowing_*` methods in core
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::TryTruncate;
+    /// assert_eq!(0x1ffu16.overflowing_truncate::<u8>(), (0xff, true));
+    /// assert_eq!((-1i32).overflowing_truncate::<i8>(), (-1, false));
+    /// ```
+    fn overflowing_truncate<T: TryTruncateFrom<Self>>(self) -> (T, bool);
+}
+
+/// Trait to truncate an integer from a larger size, clamping to the bounds of the smaller type
+/// instead of wrapping.
+///
+/// This supports the same type pairs as [`Truncate`], so `u16` to `i8` will *not* compile.
+pub trait SaturatingTruncate: Truncate {
+    /// Truncates an integer to a smaller integer, returning `T::MIN` or `T::MAX` if the value does
+    /// not fit
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::SaturatingTruncate;
+    /// assert_eq!(40_000i32.saturating_truncate::<i16>(), i16::MAX);
+    /// assert_eq!((-40_000i32).saturating_truncate::<i16>(), i16::MIN);
+    /// assert_eq!(1234i32.saturating_truncate::<i16>(), 1234);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::SaturatingTruncate;
+    /// let val = 0u16.saturating_truncate::<i8>();
+    /// ```
+    fn saturating_truncate<T: SaturatingTruncateFrom<Self>>(self) -> T;
+}
+
+/// Trait to truncate an integer from a larger size, keeping the high bits instead of the low
+/// bits.
+///
+/// This is useful for fixed point math, hashing, and taking the high half of a widened multiply,
+/// without writing shifts and `as` casts by hand.
+///
+/// This supports the same type pairs as [`Truncate`], so `u16` to `i8` will *not* compile.
+pub trait TruncateHigh: Truncate {
+    /// Truncates an integer to a smaller integer, returning its most significant `T::BITS` bits
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::TruncateHigh;
+    /// assert_eq!(0x1234_5678_9abc_def0u64.truncate_high::<u32>(), 0x1234_5678);
+    /// assert_eq!(0x1234u16.truncate_high::<u8>(), 0x12);
+    /// assert_eq!((-2i32).truncate_high::<i16>(), -1);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::TruncateHigh;
+    /// let val = 0u16.truncate_high::<i8>();
+    /// ```
+    fn truncate_high<T: TruncateHighFrom<Self>>(self) -> T;
+}
+
+/// Trait to split an unsigned integer into its high and low halves, and join them back.
+///
+/// This is built from [`TruncateHigh`], [`Truncate`], and [`Widen`], so you never need to write the
+/// shifts and masks by hand.
+pub trait Split: Sealed + Sized {
+    /// The unsigned integer that is half the width of this integer
+    type Half;
+
+    /// Splits an integer into its `(high, low)` halves
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::Split;
+    /// assert_eq!(0x1234_5678_9abc_def0u64.split(), (0x1234_5678u32, 0x9abc_def0u32));
+    /// ```
+    fn split(self) -> (Self::Half, Self::Half);
+
+    /// Joins the `high` and `low` halves of an integer
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::Split;
+    /// assert_eq!(u64::join(0x1234_5678, 0x9abc_def0), 0x1234_5678_9abc_def0);
+    /// ```
+    fn join(high: Self::Half, low: Self::Half) -> Self;
+}
+
+/// Trait to split an unsigned integer into an array of narrower unsigned lanes, and join them
+/// back.
+///
+/// Lanes are ordered by significance, which is named by each method, rather than by memory
+/// endianness like [`to_be_bytes`](u32::to_be_bytes).
+pub trait SplitLanes: Sealed + Sized {
+    /// Splits an integer into lanes of type `T`, ordered from most significant to least
+    /// significant
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::SplitLanes;
+    /// assert_eq!(0x1234_5678u32.to_lanes_high_first::<u8>(), [0x12, 0x34, 0x56, 0x78]);
+    /// assert_eq!(0x1234_5678u32.to_lanes_high_first::<u16>(), [0x1234, 0x5678]);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::SplitLanes;
+    /// let lanes = 0u16.to_lanes_high_first::<u32>();
+    /// ```
+    fn to_lanes_high_first<T: LaneOf<Self>>(self) -> T::Lanes;
+
+    /// Splits an integer into lanes of type `T`, ordered from least significant to most
+    /// significant
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::SplitLanes;
+    /// assert_eq!(0x1234_5678u32.to_lanes_low_first::<u8>(), [0x78, 0x56, 0x34, 0x12]);
+    /// ```
+    fn to_lanes_low_first<T: LaneOf<Self>>(self) -> T::Lanes;
+
+    /// Joins lanes of type `T`, ordered from most significant to least significant, into an
+    /// integer
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::SplitLanes;
+    /// assert_eq!(u32::from_lanes_high_first::<u8>([0x12, 0x34, 0x56, 0x78]), 0x1234_5678);
+    /// ```
+    fn from_lanes_high_first<T: LaneOf<Self>>(lanes: T::Lanes) -> Self;
+
+    /// Joins lanes of type `T`, ordered from least significant to most significant, into an
+    /// integer
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::SplitLanes;
+    /// assert_eq!(u32::from_lanes_low_first::<u16>([0x5678, 0x1234]), 0x1234_5678);
+    /// ```
+    fn from_lanes_low_first<T: LaneOf<Self>>(lanes: T::Lanes) -> Self;
+}
+
+/// Trait to extract and insert bit fields of an unsigned integer, with the field position and
+/// width given as const generics.
+///
+/// This is useful for register and packet header manipulation, and is checked at compile time, so
+/// `LEN` must be at least 1, must fit in the field type, and `LO + LEN` must fit in the integer.
+/// Fields are always zero extended, use [`ExtendFrom`] to sign extend them.
+///
+/// The methods are named `*_field` rather than `*_bits`, as `extract_bits` collides with an
+/// unstable inherent method of core, which gathers bits by a mask instead.
+pub trait BitField: Sealed + Sized {
+    /// Extracts the `LEN` bit wide field starting at bit `LO`, as an integer of type `T`
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::BitField;
+    /// let reg = 0xdead_beefu32;
+    /// assert_eq!(reg.extract_field::<8, 8, u8>(), 0xbe);
+    /// assert_eq!(reg.extract_field::<4, 12, u16>(), 0xbee);
+    /// ```
+    /// But this wont compile, as 12 bits do not fit in a `u8`:
+    /// ```compile_fail
+    /// # use explicit_cast::BitField;
+    /// let field = 0u32.extract_field::<4, 12, u8>();
+    /// ```
+    fn extract_field<const LO: u32, const LEN: u32, T: BitFieldOf<Self>>(self) -> T;
+
+    /// Inserts the low `LEN` bits of `field` starting at bit `LO`, leaving all other bits intact
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::BitField;
+    /// assert_eq!(0xdead_beefu32.insert_field::<8, 8>(0x42u8), 0xdead_42ef);
+    /// assert_eq!(0u16.insert_field::<12, 4>(0xffu8), 0xf000);
+    /// ```
+    /// But this wont compile, as the field does not fit in a `u16`:
+    /// ```compile_fail
+    /// # use explicit_cast::BitField;
+    /// let reg = 0u16.insert_field::<12, 8>(0u8);
+    /// ```
+    #[must_use]
+    fn insert_field<const LO: u32, const LEN: u32>(self, field: impl BitFieldOf<Self>) -> Self;
+}
+
 /// Trait to widen an integer from a smaller size, either zero extending or sign extending
 /// depending on whether the integer is signed.
 ///
+/// This is also implemented for [`f32`] to [`f64`], which is always exact.
+///
+/// Pointer sized integers also widen both into and from the fixed width integer of the same
+/// width, i/e `usize` and `u64` on 64 bit targets, so that code converting between them is
+/// lossless on every target it compiles on. Every other pair is strictly wider.
+///
 /// This is better than `as` casting because:
-/// - It is explicitly a widening operation, and will *only* widen
+/// - It is explicitly a widening operation, and will *only* widen, or convert between a pointer
+///   sized integer and a fixed width integer of the same width
 /// - It only supports similar signs, i/e `u8` to `i16` will *not* compile
 /// - It is method chainable
 /// - You can use turbofishy :D or type inference, unlike [`into`](Into::into) which only supports type inference
@@ -127,7 +826,8 @@ pub trait Truncate: Sealed + Sized {
 /// Error messages should also be clear in the event of an invalid operation, so you will not be
 /// left wondering what went wrong, this is mostly thanks to rusts great error messages though
 pub trait Widen: Sealed + Sized {
-    /// Widens an integer to a larger integer
+    /// Widens an integer to a larger integer, or between a pointer sized integer and the fixed
+    /// width integer of the same width
     ///
     /// # Examples
     /// ```
@@ -142,6 +842,404 @@ pub trait Widen: Sealed + Sized {
     fn widen<T: WidenFrom<Self>>(self) -> T;
 }
 
+/// Trait to widen an integer from a smaller size, with an explicitly named extension mode.
+///
+/// [`Widen`] picks zero extension or sign extension from the signedness of the source integer,
+/// while this trait names the extension mode at the call site, and works between any signedness,
+/// as long as the target integer is strictly wider.
+pub trait WidenExtend: Sealed + Sized {
+    /// Widens an integer to a larger integer, filling the new high bits with zeroes
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::WidenExtend;
+    /// assert_eq!((-1i8).zero_extend::<i32>(), 0xff);
+    /// assert_eq!(0x80u8.zero_extend::<u32>(), 0x80);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::WidenExtend;
+    /// let val: u16 = 0i16.zero_extend();
+    /// ```
+    fn zero_extend<T: WidenExtendFrom<Self>>(self) -> T;
+
+    /// Widens an integer to a larger integer, filling the new high bits with copies of its most
+    /// significant bit
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::WidenExtend;
+    /// assert_eq!(0x80u8.sign_extend::<u32>(), 0xffff_ff80);
+    /// assert_eq!(0x7fu8.sign_extend::<i64>(), 0x7f);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::WidenExtend;
+    /// let val: u8 = 0u16.sign_extend();
+    /// ```
+    fn sign_extend<T: WidenExtendFrom<Self>>(self) -> T;
+}
+
+/// Trait for arithmetic that produces a result twice as wide as its operands, so that it can never
+/// overflow.
+///
+/// Each operand is widened into [`Double`](Widening::Double) through [`WidenFrom`] before the
+/// operation, so this is available on stable, without any `as` casts. This is implemented for
+/// every [`WiderInt`], which is every integer except `u128` and `i128`.
+///
+/// The methods are suffixed with `_double` rather than named `widening_mul` and `carrying_mul`,
+/// as those names collide with unstable inherent methods of core that return `(low, high)` halves
+/// instead. The collision warns through `unstable_name_collisions` today, and the inherent methods
+/// would take precedence once stabilized. `add_double` shares the suffix for consistency, core has
+/// no `widening_add`.
+pub trait Widening: WiderInt {
+    /// The integer that is twice as wide as this integer, with the same signedness, this is
+    /// always [`WiderInt::Wider`]
+    type Double: WidenFrom<Self>;
+
+    /// Multiplies two integers, returning the full double width product
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::Widening;
+    /// assert_eq!(u32::MAX.mul_double(u32::MAX), 0xffff_fffe_0000_0001u64);
+    /// assert_eq!(i64::MIN.mul_double(-1), 1i128 << 63);
+    /// ```
+    fn mul_double(self, rhs: Self) -> Self::Double;
+
+    /// Adds two integers, returning the full double width sum
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::Widening;
+    /// assert_eq!(u8::MAX.add_double(1), 256u16);
+    /// assert_eq!(i8::MIN.add_double(-1), -129i16);
+    /// ```
+    fn add_double(self, rhs: Self) -> Self::Double;
+
+    /// Multiplies two integers and adds `carry`, returning the full double width result
+    ///
+    /// This can never overflow, so it is the building block of bignum multiplication, where the
+    /// high half of the result is the carry into the next limb.
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{Split, Widening};
+    /// assert_eq!(u64::MAX.carrying_mul_double(u64::MAX, u64::MAX).split(), (u64::MAX, 0));
+    /// ```
+    fn carrying_mul_double(self, rhs: Self, carry: Self) -> Self::Double;
+}
+
+/// Trait to losslessly widen an unsigned integer into a larger signed integer.
+///
+/// This is better than chaining [`Widen`] and [`SignCast`] because:
+/// - It is a single operation that can never change the numeric value
+/// - It only supports a signed target that is strictly wider, i/e `u8` to `i8` will *not* compile
+/// - You can use turbofishy or type inference, just like [`Widen`]
+pub trait WidenSigned: Sealed + Sized {
+    /// Widens an unsigned integer to a larger signed integer
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::WidenSigned;
+    /// assert_eq!(u8::MAX.widen_signed::<i16>(), 255);
+    /// assert_eq!(u32::MAX.widen_signed::<i64>(), 0xffff_ffff);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::WidenSigned;
+    /// let val: i16 = 0u16.widen_signed();
+    /// ```
+    fn widen_signed<T: WidenSignedFrom<Self>>(self) -> T;
+}
+
+/// Trait to cast between any two integers, changing width and sign in a single operation, while
+/// checking that the numeric value does not change.
+///
+/// This is better than chaining [`SignCast`] and [`Truncate`] because neither of those can fail,
+/// and will silently change the value of an integer that does not fit.
+pub trait CastChecked: Sealed + Sized {
+    /// Casts an integer to any other integer, failing if the value is not representable in `T`
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] of kind [`CastErrorKind::NegativeToUnsigned`] if a negative value is
+    /// cast to an unsigned integer, [`CastErrorKind::Underflow`] if a negative value is below
+    /// `T::MIN`, or [`CastErrorKind::Overflow`] if a value is above `T::MAX`
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{CastChecked, CastErrorKind};
+    /// assert_eq!(200i32.cast_checked::<u8>(), Ok(200));
+    /// assert_eq!(300i32.cast_checked::<u8>().unwrap_err().kind(), CastErrorKind::Overflow);
+    /// assert_eq!((-1i32).cast_checked::<u8>().unwrap_err().kind(), CastErrorKind::NegativeToUnsigned);
+    /// assert_eq!((-200i32).cast_checked::<i8>().unwrap_err().kind(), CastErrorKind::Underflow);
+    /// ```
+    fn cast_checked<T: CastCheckedFrom<Self>>(self) -> Result<T, CastError<Self>>;
+}
+
+/// Trait to narrow a float to a smaller float.
+///
+/// This is better than `as` casting because:
+/// - It is explicitly a narrowing operation, and may lose precision or overflow to infinity
+/// - It has a checked companion that only succeeds if no precision is lost
+/// - It is method chainable
+/// - You can use turbofishy or type inference
+pub trait Narrow: Sealed + Sized {
+    /// Narrows a float to a smaller float, rounding to the nearest representable value, and
+    /// producing infinity if it is out of range
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::Narrow;
+    /// assert_eq!(0.5f64.narrow::<f32>(), 0.5);
+    /// assert_eq!(f64::MAX.narrow::<f32>(), f32::INFINITY);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::Narrow;
+    /// let val: f64 = 0f32.narrow();
+    /// ```
+    fn narrow<T: NarrowFrom<Self>>(self) -> T;
+
+    /// Narrows a float to a smaller float, failing if the value would not round trip exactly
+    ///
+    /// `NaN` is always accepted, and narrows to a `NaN`, though its payload may not be preserved.
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] of kind [`CastErrorKind::Overflow`] or
+    /// [`CastErrorKind::Underflow`] if a finite value is outside of `T`'s finite range, or
+    /// [`CastErrorKind::Inexact`] if it would be rounded
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{CastErrorKind, Narrow};
+    /// assert_eq!(0.5f64.try_narrow::<f32>(), Ok(0.5));
+    /// assert_eq!(0.1f64.try_narrow::<f32>().unwrap_err().kind(), CastErrorKind::Inexact);
+    /// assert_eq!(f64::MAX.try_narrow::<f32>().unwrap_err().kind(), CastErrorKind::Overflow);
+    /// ```
+    fn try_narrow<T: NarrowFrom<Self>>(self) -> Result<T, CastError<Self>>;
+}
+
+/// Trait to convert an integer to a float, only where the conversion is always exact.
+///
+/// This is better than `as` casting because:
+/// - It will never round, `u64` to `f64` will *not* compile, as values above 2^53 would be rounded
+/// - It is method chainable
+/// - You can use turbofishy or type inference
+pub trait ToFloat: Sealed + Sized {
+    /// Converts an integer to a float that can represent all of its values exactly
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::ToFloat;
+    /// assert_eq!(u32::MAX.to_float::<f64>(), 4_294_967_295.0);
+    /// assert_eq!(i16::MIN.to_float::<f32>(), -32_768.0);
+    /// ```
+    /// But this wont compile:
+    /// ```compile_fail
+    /// # use explicit_cast::ToFloat;
+    /// let val: f64 = 0u64.to_float();
+    /// ```
+    fn to_float<T: FloatFrom<Self>>(self) -> T;
+}
+
+/// Trait to convert an integer to a float where the conversion may round.
+///
+/// Unlike [`ToFloat`], this supports every integer and float pair, so precision loss is visible
+/// at the call site instead of hidden behind an `as` cast.
+pub trait ToFloatRounded: Sealed + Sized {
+    /// Converts an integer to a float, rounding to the nearest representable value with ties to
+    /// even, this is what `as` casting does
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::ToFloatRounded;
+    /// assert_eq!((1u64 << 53 | 1).to_float_rounded::<f64>(), 9_007_199_254_740_992.0);
+    /// assert_eq!(u32::MAX.to_float_rounded::<f32>(), 4_294_967_296.0);
+    /// ```
+    fn to_float_rounded<T: RoundedFloatFrom<Self>>(self) -> T;
+
+    /// Converts an integer to a float, failing if the integer is not exactly representable
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] of kind [`CastErrorKind::Inexact`] if the value would be rounded,
+    /// or [`CastErrorKind::Overflow`] if it is beyond the finite range of `T`
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{CastErrorKind, ToFloatRounded};
+    /// assert_eq!((1u64 << 60).try_to_float_exact::<f64>(), Ok(1_152_921_504_606_846_976.0));
+    /// assert_eq!(u64::MAX.try_to_float_exact::<f64>().unwrap_err().kind(), CastErrorKind::Inexact);
+    /// assert_eq!(u128::MAX.try_to_float_exact::<f32>().unwrap_err().kind(), CastErrorKind::Overflow);
+    /// ```
+    fn try_to_float_exact<T: RoundedFloatFrom<Self>>(self) -> Result<T, CastError<Self>>;
+}
+
+/// Trait to convert a float to an integer with an explicitly named rounding mode.
+///
+/// This is better than `as` casting because:
+/// - The rounding mode is always named at the call site, instead of silently rounding toward zero
+/// - Saturation at the bounds of the integer is explicit, and has checked alternatives that
+///   reject `NaN` and out of range values
+/// - It is method chainable
+/// - You can use turbofishy or type inference
+pub trait ToInt: Sealed + Sized {
+    /// Converts a float to an integer using the given rounding mode, saturating at `T::MIN` and
+    /// `T::MAX`, and converting `NaN` to `0`
+    ///
+    /// With [`Rounding::TowardZero`] this is exactly the semantics of an `as` cast.
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{Rounding, ToInt};
+    /// assert_eq!((-2.5f64).saturating_to_int::<i32>(Rounding::TowardZero), -2);
+    /// assert_eq!((-2.5f64).saturating_to_int::<i32>(Rounding::NearestEven), -2);
+    /// assert_eq!((-2.5f64).saturating_to_int::<i32>(Rounding::Floor), -3);
+    /// assert_eq!((-2.5f64).saturating_to_int::<i32>(Rounding::Ceil), -2);
+    /// assert_eq!(1e10f32.saturating_to_int::<i32>(Rounding::TowardZero), i32::MAX);
+    /// assert_eq!(f64::NAN.saturating_to_int::<u8>(Rounding::Floor), 0);
+    /// ```
+    fn saturating_to_int<T: IntFrom<Self>>(self, mode: Rounding) -> T;
+
+    /// Converts a float to an integer using the given rounding mode, failing if the rounded value
+    /// is not representable in `T`
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] of kind [`CastErrorKind::NaN`] if the value is `NaN`, or
+    /// [`CastErrorKind::Overflow`], [`CastErrorKind::Underflow`], or
+    /// [`CastErrorKind::NegativeToUnsigned`] if the rounded value is out of range
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{CastErrorKind, Rounding, ToInt};
+    /// assert_eq!(255.4f32.try_to_int::<u8>(Rounding::NearestEven), Ok(255));
+    /// assert_eq!(255.5f32.try_to_int::<u8>(Rounding::NearestEven).unwrap_err().kind(), CastErrorKind::Overflow);
+    /// assert_eq!((-0.5f32).try_to_int::<u8>(Rounding::Ceil), Ok(0));
+    /// assert_eq!(f32::NAN.try_to_int::<u8>(Rounding::Ceil).unwrap_err().kind(), CastErrorKind::NaN);
+    /// ```
+    fn try_to_int<T: IntFrom<Self>>(self, mode: Rounding) -> Result<T, CastError<Self>>;
+
+    /// Converts a float to an integer, failing if it has a fractional part or is otherwise not
+    /// exactly representable in `T`
+    ///
+    /// # Errors
+    /// Returns a [`CastError`] of kind [`CastErrorKind::Inexact`] if the value has a fractional
+    /// part, or any of the errors that [`try_to_int`](ToInt::try_to_int) returns
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::{CastErrorKind, ToInt};
+    /// assert_eq!((-3.0f64).try_to_int_exact::<i8>(), Ok(-3));
+    /// assert_eq!(3.5f64.try_to_int_exact::<i8>().unwrap_err().kind(), CastErrorKind::Inexact);
+    /// ```
+    fn try_to_int_exact<T: IntFrom<Self>>(self) -> Result<T, CastError<Self>>;
+}
+
+/// Trait exposing metadata about an integer type, for use in generic code over the integers
+/// supported by this crate.
+///
+/// # Examples
+/// ```
+/// use explicit_cast::Int;
+///
+/// fn as_unsigned_bits<T: Int>(x: T) -> T::Unsigned {
+///     x.to_unsigned()
+/// }
+///
+/// assert_eq!(as_unsigned_bits(-1i8), 0xffu8);
+/// assert_eq!(<u16 as Int>::BITS, 16);
+/// assert!(<i32 as Int>::IS_SIGNED);
+/// ```
+/// The associated types are shared with [`ExtendFrom`], so generic sign extension is unambiguous:
+/// ```
+/// use explicit_cast::{ExtendFrom, Int};
+///
+/// fn nibble<T: Int + ExtendFrom>(x: T) -> T::Signed {
+///     x.sign_extend_from::<4>()
+/// }
+///
+/// assert_eq!(nibble(0x0fu8), -1i8);
+/// ```
+pub trait Int:
+    Sealed
+    + Copy
+    + Eq
+    + Ord
+    + core::hash::Hash
+    + core::fmt::Debug
+    + core::fmt::Display
+    + Default
+    + Send
+    + Sync
+    + 'static
+{
+    /// The width of this integer in bits
+    const BITS: u32;
+    /// Whether this integer is signed
+    const IS_SIGNED: bool;
+    /// The smallest value of this integer
+    const MIN: Self;
+    /// The largest value of this integer
+    const MAX: Self;
+
+    /// The unsigned integer of the same width, which is [`Self`] if it is unsigned
+    type Unsigned: Int;
+    /// The signed integer of the same width, which is [`Self`] if it is signed
+    type Signed: Int;
+
+    /// Reinterprets this integer as the unsigned integer of the same width, like
+    /// [`SignCast`] but also implemented for unsigned integers, where it does nothing
+    fn to_unsigned(self) -> Self::Unsigned;
+
+    /// Reinterprets this integer as the signed integer of the same width, like [`SignCast`] but
+    /// also implemented for signed integers, where it does nothing
+    fn to_signed(self) -> Self::Signed;
+}
+
+/// Trait for integers that have a next wider integer of the same signedness.
+///
+/// This is implemented for every integer except `u128` and `i128`. The wider step of a pointer
+/// sized integer is the integer twice its width.
+pub trait WiderInt: Int {
+    /// The next wider integer, with the same signedness
+    type Wider: Int + WidenFrom<Self>;
+
+    /// Widens this integer to the next wider integer
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::WiderInt;
+    /// fn square<T: WiderInt>(x: T) -> T::Wider
+    /// where
+    ///     T::Wider: core::ops::Mul<Output = T::Wider>,
+    /// {
+    ///     x.widen_step() * x.widen_step()
+    /// }
+    ///
+    /// assert_eq!(square(u8::MAX), 65025u16);
+    /// ```
+    fn widen_step(self) -> Self::Wider;
+}
+
+/// Trait for integers that have a next narrower integer of the same signedness.
+///
+/// This is implemented for every integer except `u8` and `i8`. The narrower step of a pointer
+/// sized integer is the integer half its width.
+pub trait NarrowerInt: Int {
+    /// The next narrower integer, with the same signedness
+    type Narrower: Int + TruncateFrom<Self>;
+
+    /// Truncates this integer to the next narrower integer
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::NarrowerInt;
+    /// assert_eq!(0x1234u16.narrow_step(), 0x34u8);
+    /// assert_eq!((-1i64).narrow_step(), -1i32);
+    /// ```
+    fn narrow_step(self) -> Self::Narrower;
+}
+
 pub mod prelude {
     //! The prelude to this crate, includes [`SignCast`], [`Truncate`], and [`Widen`] imported for
     //! you
@@ -155,3 +1253,83 @@ fn cast_works() {
         0
     );
 }
+
+#[test]
+fn ordered_bits_match_total_order() {
+    let floats = [
+        -f64::NAN,
+        f64::NEG_INFINITY,
+        f64::MIN,
+        -1.0,
+        -f64::MIN_POSITIVE,
+        -0.0,
+        0.0,
+        f64::MIN_POSITIVE,
+        1.0,
+        f64::MAX,
+        f64::INFINITY,
+        f64::NAN,
+    ];
+
+    for pair in floats.windows(2) {
+        assert!(pair[0].total_cmp(&pair[1]).is_lt());
+        assert!(pair[0].to_ordered_bits() < pair[1].to_ordered_bits());
+    }
+
+    for f in floats {
+        assert_eq!(
+            f64::from_ordered_bits(f.to_ordered_bits()).to_bits(),
+            f.to_bits()
+        );
+    }
+}
+
+#[test]
+fn sign_reprs_round_trip() {
+    for v in i8::MIN + 1..=i8::MAX {
+        assert_eq!(
+            i8::from_sign_magnitude(v.try_to_sign_magnitude().unwrap()),
+            v
+        );
+        assert_eq!(
+            i8::from_ones_complement(v.try_to_ones_complement().unwrap()),
+            v
+        );
+    }
+}
+
+#[test]
+#[cfg(target_pointer_width = "64")]
+fn pointer_sized_cast_works() {
+    assert_eq!(u32::MAX.widen::<usize>().widen::<u128>(), 0xffff_ffff);
+    assert_eq!(
+        (-1i64).widen::<isize>().sign_cast().truncate::<u32>(),
+        u32::MAX
+    );
+    assert_eq!(u128::MAX.truncate::<usize>(), usize::MAX);
+
+    assert_eq!(u64::MAX.widen::<usize>().widen::<u64>(), u64::MAX);
+    assert_eq!(i64::MIN.widen::<isize>().widen::<i64>(), i64::MIN);
+}
+
+#[test]
+#[cfg(target_pointer_width = "32")]
+fn pointer_sized_cast_works_32() {
+    assert_eq!(u16::MAX.widen::<usize>().widen::<u64>(), 0xffff);
+    assert_eq!(u64::MAX.truncate::<usize>(), usize::MAX);
+    assert_eq!(usize::MAX.truncate::<u16>(), u16::MAX);
+
+    assert_eq!(u32::MAX.widen::<usize>().widen::<u32>(), u32::MAX);
+    assert_eq!(i32::MIN.widen::<isize>().widen::<i32>(), i32::MIN);
+}
+
+#[test]
+#[cfg(target_pointer_width = "16")]
+fn pointer_sized_cast_works_16() {
+    assert_eq!(u8::MAX.widen::<usize>().widen::<u32>(), 0xff);
+    assert_eq!(u32::MAX.truncate::<usize>(), usize::MAX);
+    assert_eq!(usize::MAX.widen::<u64>(), 0xffff);
+
+    assert_eq!(u16::MAX.widen::<usize>().widen::<u16>(), u16::MAX);
+    assert_eq!(i16::MIN.widen::<isize>().widen::<i16>(), i16::MIN);
+}
@@ -0,0 +1,211 @@
+//! `no_std`, no-alloc [LEB128](https://en.wikipedia.org/wiki/LEB128) varint encoding and decoding.
+//!
+//! Unsigned integers use unsigned LEB128, and signed integers use signed LEB128, if you want
+//! protobuf style `sint` encoding, [`ZigZag`](crate::ZigZag) encode the value first.
+//!
+//! Decoding never truncates, if the encoded value does not fit in the requested type, a
+//! [`VarintError::OutOfRange`] is returned instead.
+//!
+//! ```
+//! use explicit_cast::varint::{Varint, VarintError};
+//!
+//! let mut buf = [0; u32::MAX_LEN];
+//! let len = 300u32.encode_varint(&mut buf)?;
+//! assert_eq!(&buf[..len], [0xac, 0x02]);
+//!
+//! assert_eq!(u16::decode_varint(&buf[..len])?, (300, 2));
+//! assert!(matches!(u8::decode_varint(&buf[..len]), Err(VarintError::OutOfRange { .. })));
+//! # Ok::<(), VarintError>(())
+//! ```
+
+use core::{any::type_name, fmt};
+
+use crate::{Sealed, SignCast};
+
+/// The error returned when encoding or decoding a varint fails.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+#[non_exhaustive]
+pub enum VarintError {
+    /// The output buffer is too small to hold the encoded value
+    BufferTooSmall,
+    /// The input ended before the final byte of the varint
+    UnexpectedEnd,
+    /// The encoded value does not fit in the target type
+    OutOfRange {
+        /// The name of the type the varint was decoded into
+        target: &'static str,
+    },
+}
+
+impl fmt::Display for VarintError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BufferTooSmall => f.write_str("buffer is too small to hold the encoded varint"),
+            Self::UnexpectedEnd => f.write_str("input ended before the end of the varint"),
+            Self::OutOfRange { target } => write!(f, "encoded varint does not fit in {target}"),
+        }
+    }
+}
+
+impl core::error::Error for VarintError {}
+
+/// Trait to encode an integer as a LEB128 varint, and decode it back into any integer type.
+pub trait Varint: Sealed + Sized {
+    /// The maximum number of bytes an encoded value of this type can occupy
+    const MAX_LEN: usize;
+
+    /// Encodes this integer into the start of `buf`, returning the number of bytes written
+    ///
+    /// # Errors
+    /// Returns [`VarintError::BufferTooSmall`] if `buf` cannot hold the encoded value, a buffer of
+    /// [`MAX_LEN`](Varint::MAX_LEN) bytes is always large enough
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::varint::Varint;
+    /// let mut buf = [0; 2];
+    /// assert_eq!((-129i32).encode_varint(&mut buf), Ok(2));
+    /// assert_eq!(buf, [0xff, 0x7e]);
+    /// assert!(u32::MAX.encode_varint(&mut buf).is_err());
+    /// ```
+    fn encode_varint(self, buf: &mut [u8]) -> Result<usize, VarintError>;
+
+    /// Decodes a varint from the start of `buf`, returning the value and the number of bytes read
+    ///
+    /// Redundant padding bytes are accepted, as long as the value still fits.
+    ///
+    /// # Errors
+    /// Returns [`VarintError::UnexpectedEnd`] if `buf` ends before the varint does, or
+    /// [`VarintError::OutOfRange`] if the value does not fit in [`Self`]
+    ///
+    /// # Examples
+    /// ```
+    /// # use explicit_cast::varint::{Varint, VarintError};
+    /// assert_eq!(i16::decode_varint(&[0xff, 0x7e]), Ok((-129, 2)));
+    /// assert_eq!(i8::decode_varint(&[0xff, 0x7e]), Err(VarintError::OutOfRange { target: "i8" }));
+    /// assert_eq!(u8::decode_varint(&[0x80]), Err(VarintError::UnexpectedEnd));
+    /// ```
+    fn decode_varint(buf: &[u8]) -> Result<(Self, usize), VarintError>;
+}
+
+/// Implements `Varint` for integers, where signed integers are given with the unsigned integer
+/// that holds their bits
+macro_rules! impl_varint {
+    (unsigned: $($t:ty),+) => {
+        $(impl_varint!(@impl $t, $t, core::convert::identity);)+
+    };
+
+    (signed: $(($t:ty, $u:ty)),+) => {
+        $(impl_varint!(@impl $t, $u, SignCast::sign_cast);)+
+    };
+
+    (@impl $t:ty, $u:ty, $from_bits:path) => {
+        impl Varint for $t {
+            #[allow(clippy::cast_possible_truncation)]
+            const MAX_LEN: usize = <$t>::BITS.div_ceil(7) as usize;
+
+            #[inline]
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            fn encode_varint(self, buf: &mut [u8]) -> Result<usize, VarintError> {
+                let mut value = self;
+
+                for (i, slot) in buf.iter_mut().enumerate() {
+                    // truncation to the low 7 bits is the point
+                    let byte = (value as u8) & 0x7f;
+
+                    // arithmetic shift for signed integers, so the remainder is 0 or -1 when done
+                    value >>= 7;
+
+                    let rest = if <$t>::MIN != 0 && byte & 0x40 != 0 { !0 } else { 0 };
+
+                    if value == rest {
+                        *slot = byte;
+                        return Ok(i + 1);
+                    }
+
+                    *slot = byte | 0x80;
+                }
+
+                Err(VarintError::BufferTooSmall)
+            }
+
+            #[inline]
+            fn decode_varint(buf: &[u8]) -> Result<(Self, usize), VarintError> {
+                // every encoded bit at or above this position must equal the sign of the value
+                const LIMIT: u32 = if <$t>::MIN == 0 { <$t>::BITS } else { <$t>::BITS - 1 };
+
+                let mut bits: $u = 0;
+                let mut high_ones = false;
+                let mut high_zeros = false;
+                let mut shift = 0u32;
+
+                for (i, &byte) in buf.iter().enumerate() {
+                    let low = byte & 0x7f;
+
+                    if shift < <$t>::BITS {
+                        bits |= <$u>::from(low) << shift;
+                    }
+
+                    if shift.saturating_add(7) > LIMIT {
+                        let below = LIMIT.saturating_sub(shift);
+                        let high = low >> below;
+
+                        high_ones |= high != 0;
+                        high_zeros |= high != 0x7f >> below;
+                    }
+
+                    shift = shift.saturating_add(7);
+
+                    if byte & 0x80 == 0 {
+                        let negative = <$t>::MIN != 0 && byte & 0x40 != 0;
+
+                        if (negative && high_zeros) || (!negative && high_ones) {
+                            return Err(VarintError::OutOfRange {
+                                target: type_name::<$t>(),
+                            });
+                        }
+
+                        if negative && shift < <$t>::BITS {
+                            bits |= <$u>::MAX << shift;
+                        }
+
+                        return Ok(($from_bits(bits), i + 1));
+                    }
+                }
+
+                Err(VarintError::UnexpectedEnd)
+            }
+        }
+    };
+}
+
+impl_varint!(unsigned: u8, u16, u32, u64, u128, usize);
+impl_varint!(signed: (i8, u8), (i16, u16), (i32, u32), (i64, u64), (i128, u128), (isize, usize));
+
+#[test]
+fn varint_round_trips() {
+    let mut buf = [0; i32::MAX_LEN];
+
+    for v in i16::MIN..=i16::MAX {
+        let len = i32::from(v).encode_varint(&mut buf).unwrap();
+        assert_eq!(i16::decode_varint(&buf[..len]), Ok((v, len)));
+        assert_eq!(
+            i8::decode_varint(&buf[..len]).ok(),
+            i8::try_from(v).ok().map(|v| (v, len))
+        );
+
+        let len = v.sign_cast().encode_varint(&mut buf).unwrap();
+        assert_eq!(u16::decode_varint(&buf[..len]), Ok((v.sign_cast(), len)));
+        assert_eq!(
+            u8::decode_varint(&buf[..len]).ok(),
+            u8::try_from(v.sign_cast()).ok().map(|v| (v, len))
+        );
+    }
+
+    for v in [i128::MIN, -1, 0, i128::MAX] {
+        let mut buf = [0; i128::MAX_LEN];
+        let len = v.encode_varint(&mut buf).unwrap();
+        assert_eq!(i128::decode_varint(&buf[..len]), Ok((v, len)));
+    }
+}